@@ -2,7 +2,8 @@
 ///
 extern crate proc_macro;
 
-use syn;
+use proc_macro2::TokenStream;
+use quote::quote;
 #[allow(unused_imports)]
 use syn::DeriveInput;
 
@@ -19,40 +20,168 @@ use enum_index_types::*;
 /// - variants
 pub fn get_enum_data(input: &DeriveInput) -> Result<&syn::DataEnum, errors::EnumIndexError> {
     if let syn::Data::Enum(data_enum) = &input.data {
-        Ok(&data_enum)
+        Ok(data_enum)
     } else {
         Err(errors::EnumIndexError::NotAnEnum(input.ident.to_string()))
     }
 }
 
-/// Check if Type matches.
-pub fn is_type(t: &syn::Type, value: &str) -> bool {
-    if let syn::Type::Path(t_path) = t {
-        t_path
-            .path
-            .segments
-            .iter()
-            .map(|seg| seg.ident.to_string())
-            .reduce(|lhs, rhs| lhs + "::" + &rhs)
-            .map(|s| s.as_str() == value)
-            .unwrap_or(false)
-    } else {
-        false
-    }
-}
-
 /// Check if an expression is a literal str.
 ///
 /// This is mostly for the special case when #[index_type(String)].
 pub fn is_lit_str(e: &syn::Expr) -> bool {
+    get_lit_str(e).is_some()
+}
+
+/// Attempt to get the literal str out of an expression.
+///
+/// Returns [`None`] if the expression is anything other than a `str` literal.
+pub fn get_lit_str(e: &syn::Expr) -> Option<&syn::LitStr> {
+    match e {
+        syn::Expr::Group(g) => get_lit_str(&g.expr),
+        syn::Expr::Lit(l) => match &l.lit {
+            syn::Lit::Str(lit_str) => Some(lit_str),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Attempt to get the textual value of a literal index, such as `"closed"` or `-3`.
+///
+/// Returns [`None`] for anything other than a `str`, `char`, integer, float or `bool`
+/// literal, possibly negated.
+pub fn lit_to_string(e: &syn::Expr) -> Option<String> {
+    match e {
+        syn::Expr::Group(g) => lit_to_string(&g.expr),
+        syn::Expr::Paren(p) => lit_to_string(&p.expr),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => lit_to_string(expr)
+            .filter(|value| value.starts_with(|c: char| c.is_ascii_digit()))
+            .map(|value| format!("-{}", value)),
+        syn::Expr::Lit(l) => match &l.lit {
+            syn::Lit::Str(lit) => Some(lit.value()),
+            syn::Lit::Char(lit) => Some(lit.value().to_string()),
+            syn::Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Build a pattern matching a variant, regardless of whether it has any fields.
+pub fn variant_pattern(variant: &syn::Variant) -> TokenStream {
+    let variant_name = &variant.ident;
+    quote!(Self::#variant_name { .. })
+}
+
+/// Build an expression constructing a variant.
+///
+/// Unit variants are constructed as is; any fields of other variants are filled with
+/// [`Default::default()`]. This is mostly for marker fields such as
+/// [`PhantomData`](std::marker::PhantomData) in generic enums.
+pub fn variant_constructor(variant: &syn::Variant) -> TokenStream {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        syn::Fields::Unit => quote!(Self::#variant_name),
+        syn::Fields::Unnamed(fields) => {
+            let defaults = fields
+                .unnamed
+                .iter()
+                .map(|_| quote!(::std::default::Default::default()));
+            quote!(Self::#variant_name(#(#defaults),*))
+        }
+        syn::Fields::Named(fields) => {
+            let defaults = fields.named.iter().map(|field| {
+                let field_name = &field.ident;
+                quote!(#field_name: ::std::default::Default::default())
+            });
+            quote!(Self::#variant_name { #(#defaults),* })
+        }
+    }
+}
+
+/// Build a copy of `generics` with an additional lifetime parameter, for use in
+/// `impl` blocks such as `impl<'de> serde::Deserialize<'de>`.
+pub fn generics_with_lifetime(generics: &syn::Generics, lifetime: &str) -> syn::Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(
+        0,
+        syn::GenericParam::Lifetime(syn::LifetimeDef::new(syn::Lifetime::new(
+            lifetime,
+            proc_macro2::Span::call_site(),
+        ))),
+    );
+    generics
+}
+
+/// Panic if an #[enum_index(...)] option was given without the feature it relies on.
+///
+/// `is_enabled` must be the `cfg!(feature = "...")` of this crate; otherwise the option
+/// would silently generate nothing.
+pub fn require_feature(input: &DeriveInput, option: &str, feature: &str, is_enabled: bool) {
+    if !is_enabled {
+        panic!(
+            "{}",
+            errors::EnumIndexError::FeatureNotEnabled(
+                input.ident.to_string(),
+                option.to_string(),
+                feature.to_string()
+            )
+        );
+    }
+}
+
+/// Collect the `///` doc comments of a variant into a single description.
+///
+/// Each line is stripped of the single leading space that `///` leaves behind; returns
+/// [`None`] if there are no doc comments.
+pub fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit),
+                ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>();
+
+    let description = lines.join("\n").trim().to_string();
+    (!description.is_empty()).then_some(description)
+}
+
+/// Check if an expression can be evaluated in a `const` context.
+///
+/// This is a conservative check: literals, paths to `const` items, and tuple-like
+/// constructors such as `Some('a')` are accepted; any other function call is not.
+pub fn is_const_expr(e: &syn::Expr) -> bool {
     match e {
-        syn::Expr::Group(g) => is_lit_str(&g.expr),
-        syn::Expr::Lit(l) => {
-            if let syn::Lit::Str(_) = l.lit {
-                true
-            } else {
-                false
-            }
+        syn::Expr::Group(g) => is_const_expr(&g.expr),
+        syn::Expr::Paren(p) => is_const_expr(&p.expr),
+        syn::Expr::Lit(_) | syn::Expr::Path(_) => true,
+        syn::Expr::Unary(u) => is_const_expr(&u.expr),
+        syn::Expr::Reference(r) => is_const_expr(&r.expr),
+        syn::Expr::Tuple(t) => t.elems.iter().all(is_const_expr),
+        syn::Expr::Array(a) => a.elems.iter().all(is_const_expr),
+        syn::Expr::Call(c) => {
+            let is_constructor = matches!(
+                &*c.func,
+                syn::Expr::Path(p) if p
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident.to_string().starts_with(char::is_uppercase))
+            );
+            is_constructor && c.args.iter().all(is_const_expr)
         }
         _ => false,
     }
@@ -0,0 +1,78 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, VariantIndex};
+
+/// Implement `arbitrary::Arbitrary`, if `#[enum_index(arbitrary = true)]` is given; this
+/// requires the `arbitrary` feature.
+///
+/// Only variants with an index are generated, so that every generated value survives a
+/// round trip through `index()` and `from_index()`; an Enum whose variants are all
+/// skipped is rejected, as there is no value to generate.
+pub fn impl_arbitrary(
+    input: &DeriveInput,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.arbitrary != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "arbitrary = true",
+        "arbitrary",
+        cfg!(feature = "arbitrary"),
+    );
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let generics = {
+        let mut generics = func::generics_with_lifetime(&input.generics, "'__a");
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#name #ty_generics: '__a));
+        generics
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let constructors = indexed_variants
+        .iter()
+        .filter(|(_, variant_index)| variant_index.expr().is_some())
+        .map(|(variant, _)| func::variant_constructor(variant))
+        .collect::<Vec<_>>();
+    if constructors.is_empty() {
+        panic!(
+            "{}",
+            errors::EnumIndexError::NoIndexToGenerate(
+                input.ident.to_string(),
+                "arbitrary = true".to_string()
+            )
+        );
+    }
+    let count = constructors.len();
+    let positions = 0..count;
+
+    quote! {
+        impl #impl_generics __private::arbitrary::Arbitrary<'__a> for #name #ty_generics
+        #where_clause
+        {
+            fn arbitrary(
+                u: &mut __private::arbitrary::Unstructured<'__a>,
+            ) -> __private::arbitrary::Result<Self> {
+                Ok(match u.choose_index(#count)? {
+                    #(#positions => #constructors,)*
+                    _ => unreachable!(),
+                })
+            }
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <u32 as __private::arbitrary::Arbitrary<'__a>>::size_hint(depth)
+            }
+        }
+    }
+}
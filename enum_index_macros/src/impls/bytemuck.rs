@@ -0,0 +1,120 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement `bytemuck::CheckedBitPattern`, if `#[enum_index(bytemuck = true)]` is given;
+/// this requires the `bytemuck` feature.
+///
+/// The enum must be fieldless and `#[repr(T)]` of its index type, so that it shares the
+/// layout of its index. The discriminant of every variant is asserted at compile time to
+/// be its index, and only these are valid bit patterns. Unlike `from_index()`, retired
+/// indices are therefore rejected: they are not the discriminant of any variant, so the
+/// bits cannot be reinterpreted as the enum. The discriminants of skipped variants are
+/// rejected too, as by `from_index()`.
+pub fn impl_checked_bit_pattern(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.bytemuck != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "bytemuck = true",
+        "bytemuck",
+        cfg!(feature = "bytemuck"),
+    );
+
+    let name = &input.ident;
+    let index_type = params.return_type_owned();
+
+    let has_repr = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| match meta {
+            syn::Meta::List(list) => list.nested.iter().any(|nested| {
+                matches!(
+                    nested,
+                    syn::NestedMeta::Meta(syn::Meta::Path(path))
+                        if path.get_ident().is_some_and(|ident| params.is_type(&ident.to_string()))
+                )
+            }),
+            _ => false,
+        });
+    if params.is_ref || !has_repr {
+        panic!(
+            "{}",
+            errors::EnumIndexError::BytemuckReprMismatch(
+                name.to_string(),
+                quote!(#index_type).to_string()
+            )
+        );
+    }
+
+    let enum_data = func::get_enum_data(input).unwrap_or_else(|err| panic!("{}", err));
+    if let Some(variant) = enum_data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, syn::Fields::Unit))
+    {
+        panic!(
+            "{}",
+            errors::EnumIndexError::BytemuckVariantHasFields(
+                name.to_string(),
+                variant.ident.to_string()
+            )
+        );
+    }
+
+    let (match_valid, assert_discriminants) = indexed_variants
+        .iter()
+        .filter_map(|(variant, variant_index)| {
+            variant_index.expr().map(|index| {
+                let ident = &variant.ident;
+                let message = format!("the discriminant of `{}::{}` is not its index", name, ident);
+
+                (
+                    // match_valid
+                    quote!(
+                        bits if bits == #name::#ident as #index_type => true,
+                    ),
+                    // assert_discriminants
+                    quote!(
+                        assert!(#name::#ident as #index_type == #index, #message);
+                    ),
+                )
+            })
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+
+    quote! {
+        const _: () = {
+            #assert_discriminants
+        };
+        // SAFETY: the enum is fieldless and `#[repr(T)]` of its index type, and only the
+        // discriminants of its variants are accepted.
+        unsafe impl __private::bytemuck::CheckedBitPattern for #name {
+            type Bits = #index_type;
+
+            fn is_valid_bit_pattern(bits: &#index_type) -> bool {
+                match *bits {
+                    #match_valid
+                    _ => false,
+                }
+            }
+        }
+    }
+}
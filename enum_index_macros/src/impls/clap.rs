@@ -0,0 +1,106 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{DisplayMode, EnumIndexOptions, VariantIndex};
+
+/// Implement `clap::ValueEnum`, if `#[enum_index(clap = "...")]` is given; this requires
+/// the `clap` feature.
+///
+/// The possible values are either the indices of the variants, or the variant names
+/// converted by `rename_all`. In `"index"` mode every index must be a literal, retired
+/// indices become aliases, and variants declared with `#[index(skip)]` are left out.
+pub fn impl_value_enum(
+    input: &DeriveInput,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    let mode = match options.clap {
+        Some(mode) => mode,
+        None => return quote!(),
+    };
+    func::require_feature(input, "clap = \"...\"", "clap", cfg!(feature = "clap"));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let rename_all = options.rename_all.unwrap_or_default();
+
+    let literal = |variant: &syn::Variant, index: &syn::Expr| {
+        func::lit_to_string(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                errors::EnumIndexError::IndexNotLiteral(
+                    variant.ident.to_string(),
+                    "#[enum_index(clap = \"index\")]".to_string()
+                )
+            )
+        })
+    };
+
+    let (value_variants, match_to_possible_value) = indexed_variants
+        .iter()
+        .map(|(variant, variant_index)| {
+            let variant_pattern = func::variant_pattern(variant);
+            let variant_constructor = func::variant_constructor(variant);
+
+            let (value, aliases) = match mode {
+                DisplayMode::Name => (
+                    Some(rename_all.apply(&variant.ident.to_string())),
+                    Vec::new(),
+                ),
+                DisplayMode::Index => (
+                    variant_index.expr().map(|index| literal(variant, index)),
+                    variant_index
+                        .deprecated()
+                        .iter()
+                        .map(|index| literal(variant, index))
+                        .collect(),
+                ),
+            };
+
+            match value {
+                Some(value) => {
+                    let help = func::doc_comment(&variant.attrs)
+                        .map(|doc| quote!(.help(#doc)))
+                        .unwrap_or_default();
+                    let aliases = if aliases.is_empty() {
+                        quote!()
+                    } else {
+                        quote!(.aliases([#(#aliases),*]))
+                    };
+
+                    (
+                        quote!(#variant_constructor,),
+                        quote!(
+                            #variant_pattern=>Some(
+                                __private::clap::builder::PossibleValue::new(#value)#help #aliases
+                            ),
+                        ),
+                    )
+                }
+                None => (quote!(), quote!(#variant_pattern=>None,)),
+            }
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+
+    quote! {
+        impl #impl_generics __private::clap::ValueEnum for #name #ty_generics #where_clause {
+            fn value_variants<'a>() -> &'a [Self] {
+                &[#value_variants]
+            }
+            fn to_possible_value(&self) -> Option<__private::clap::builder::PossibleValue> {
+                match self {
+                    #match_to_possible_value
+                }
+            }
+        }
+    }
+}
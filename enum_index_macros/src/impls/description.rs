@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::func;
+use crate::structs::{EnumIndexParams, VariantIndex};
+
+/// Build `description()` and the `INDEX_TABLE` constant from the doc comments of the
+/// variants.
+///
+/// These go inside the inherent `impl` block of the enum. `INDEX_TABLE` is only built if
+/// every index can be evaluated in a `const` context; see [`func::is_const_expr`].
+pub fn impl_descriptions(
+    params: &EnumIndexParams,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    let descriptions = indexed_variants
+        .iter()
+        .map(|(variant, _)| match func::doc_comment(&variant.attrs) {
+            Some(description) => quote!(Some(#description)),
+            None => quote!(None),
+        })
+        .collect::<Vec<_>>();
+
+    let match_to_description =
+        indexed_variants
+            .iter()
+            .zip(descriptions.iter())
+            .map(|((variant, _), description)| {
+                let variant_pattern = func::variant_pattern(variant);
+                quote!(
+                    #variant_pattern=>#description,
+                )
+            });
+
+    let is_const = indexed_variants
+        .iter()
+        .filter_map(|(_, variant_index)| variant_index.expr())
+        .all(|index| {
+            if params.is_str_like() {
+                func::is_lit_str(index)
+            } else {
+                func::is_const_expr(index)
+            }
+        });
+
+    let index_table = if is_const {
+        // `str` indices are stored as `&'static str` regardless of the index type.
+        let table_index_type = if params.is_str_like() {
+            quote!(&'static str)
+        } else {
+            let return_type = params.return_type_specified();
+            quote!(#return_type)
+        };
+
+        let entries = indexed_variants.iter().zip(descriptions.iter()).map(
+            |((variant, variant_index), description)| {
+                let ident_str = variant.ident.to_string();
+                let index = match variant_index.expr() {
+                    Some(index) => quote!(Some(#index)),
+                    None => quote!(None),
+                };
+                quote!(
+                    (#ident_str, #index, #description),
+                )
+            },
+        );
+
+        quote! {
+            /// The name, index and description of every variant, in declaration order.
+            ///
+            /// The index is [`None`] for variants declared with `#[index(skip)]`.
+            pub const INDEX_TABLE: &'static [(
+                &'static str,
+                Option<#table_index_type>,
+                Option<&'static str>,
+            )] = &[
+                #(#entries)*
+            ];
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        /// Get the doc comment of this variant, if any.
+        pub fn description(&self) -> Option<&'static str> {
+            match self {
+                #(#match_to_description)*
+            }
+        }
+        #index_table
+    }
+}
@@ -0,0 +1,193 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement `ToSql`, `FromSql`, `AsExpression` and `FromSqlRow` of `diesel`, if
+/// `#[enum_index(diesel(sql_type = T))]` is given; this requires the `diesel` feature.
+///
+/// These are the same implementations as `#[derive(AsExpression, FromSqlRow)]` of
+/// `diesel`, for the given SQL type only. Backends such as SQLite keep a reference to the
+/// value until the query is executed, so `ToSql` writes a `'static` reference to the
+/// index of each variant; the indices must therefore be constants, and `str` indices must
+/// be literals.
+pub fn impl_diesel(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    let sql_type = match &options.diesel {
+        Some(sql_type) => sql_type,
+        None => return quote!(),
+    };
+    func::require_feature(input, "diesel(...)", "diesel", cfg!(feature = "diesel"));
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let input_type = params.input_type();
+    let return_type_ref = params.return_type_ref();
+
+    // `str` indices are written as `str`, regardless of the index type.
+    let to_sql_type = if params.is_str_like() {
+        quote!(str)
+    } else {
+        let return_type = params.return_type_specified();
+        quote!(#return_type)
+    };
+
+    let match_to_static_index = indexed_variants.iter().map(|(variant, variant_index)| {
+        let variant_pattern = func::variant_pattern(variant);
+        let ident_str = variant.ident.to_string();
+
+        match variant_index.expr() {
+            Some(index) if params.is_str_like() => {
+                let lit = func::get_lit_str(index).unwrap_or_else(|| {
+                    panic!(
+                        "{}",
+                        errors::EnumIndexError::IndexNotLiteral(
+                            ident_str,
+                            "#[enum_index(diesel(...))]".to_string()
+                        )
+                    )
+                });
+                quote!(#variant_pattern=>#lit,)
+            }
+            Some(index) => quote!(#variant_pattern=>&#index,),
+            None => quote!(
+                #variant_pattern=>return Err(Box::new(
+                    EnumIndexError::VariantSkipped(#ident_str.to_string())
+                )),
+            ),
+        }
+    });
+
+    let build_generics = |generics: &syn::Generics, predicates: Vec<syn::WherePredicate>| {
+        let mut generics = generics.clone();
+        generics.params.push(parse_quote!(__DB));
+        generics.make_where_clause().predicates.extend(predicates);
+        generics
+    };
+    let to_sql_generics = build_generics(
+        &input.generics,
+        vec![
+            parse_quote!(__DB: __private::diesel::backend::Backend),
+            parse_quote!(#to_sql_type: __private::diesel::serialize::ToSql<#sql_type, __DB>),
+        ],
+    );
+    let to_sql_nullable_generics = build_generics(
+        &input.generics,
+        vec![
+            parse_quote!(__DB: __private::diesel::backend::Backend),
+            parse_quote!(Self: __private::diesel::serialize::ToSql<#sql_type, __DB>),
+        ],
+    );
+    let from_sql_generics = build_generics(
+        &input.generics,
+        vec![
+            parse_quote!(__DB: __private::diesel::backend::Backend),
+            parse_quote!(#input_type: __private::diesel::deserialize::FromSql<#sql_type, __DB>),
+        ],
+    );
+    let queryable_generics = {
+        let mut generics = build_generics(
+            &input.generics,
+            vec![
+                parse_quote!(__DB: __private::diesel::backend::Backend),
+                parse_quote!(__ST: __private::diesel::sql_types::SingleValue),
+                parse_quote!(Self: __private::diesel::deserialize::FromSql<__ST, __DB>),
+            ],
+        );
+        generics.params.push(parse_quote!(__ST));
+        generics
+    };
+    let expr_generics = func::generics_with_lifetime(&input.generics, "'__expr");
+
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+    let (to_sql_nullable_impl_generics, _, to_sql_nullable_where_clause) =
+        to_sql_nullable_generics.split_for_impl();
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+    let (queryable_impl_generics, _, queryable_where_clause) = queryable_generics.split_for_impl();
+    let (expr_impl_generics, _, _) = expr_generics.split_for_impl();
+
+    let nullable_sql_type = quote!(__private::diesel::sql_types::Nullable<#sql_type>);
+    let bound = quote!(__private::DieselBound);
+
+    let impl_as_expression = [quote!(#sql_type), nullable_sql_type.clone()]
+        .into_iter()
+        .map(|sql_type| {
+            quote! {
+                impl #expr_impl_generics __private::diesel::expression::AsExpression<#sql_type>
+                    for &'__expr #name #ty_generics #where_clause
+                {
+                    type Expression = #bound<#sql_type, Self>;
+                    fn as_expression(self) -> Self::Expression {
+                        #bound::new(self)
+                    }
+                }
+                impl #impl_generics __private::diesel::expression::AsExpression<#sql_type>
+                    for #name #ty_generics #where_clause
+                {
+                    type Expression = #bound<#sql_type, Self>;
+                    fn as_expression(self) -> Self::Expression {
+                        #bound::new(self)
+                    }
+                }
+            }
+        });
+
+    quote! {
+        impl #to_sql_impl_generics __private::diesel::serialize::ToSql<#sql_type, __DB>
+            for #name #ty_generics #to_sql_where_clause
+        {
+            fn to_sql<'__b>(
+                &'__b self,
+                out: &mut __private::diesel::serialize::Output<'__b, '_, __DB>,
+            ) -> __private::diesel::serialize::Result {
+                let index: &'static #to_sql_type = match self {
+                    #(#match_to_static_index)*
+                };
+                <#to_sql_type as __private::diesel::serialize::ToSql<#sql_type, __DB>>::to_sql(
+                    index, out,
+                )
+            }
+        }
+        impl #to_sql_nullable_impl_generics
+            __private::diesel::serialize::ToSql<#nullable_sql_type, __DB>
+            for #name #ty_generics #to_sql_nullable_where_clause
+        {
+            fn to_sql<'__b>(
+                &'__b self,
+                out: &mut __private::diesel::serialize::Output<'__b, '_, __DB>,
+            ) -> __private::diesel::serialize::Result {
+                __private::diesel::serialize::ToSql::<#sql_type, __DB>::to_sql(self, out)
+            }
+        }
+        impl #from_sql_impl_generics __private::diesel::deserialize::FromSql<#sql_type, __DB>
+            for #name #ty_generics #from_sql_where_clause
+        {
+            fn from_sql(
+                bytes: <__DB as __private::diesel::backend::Backend>::RawValue<'_>,
+            ) -> __private::diesel::deserialize::Result<Self> {
+                let index = <#input_type as __private::diesel::deserialize::FromSql<
+                    #sql_type,
+                    __DB,
+                >>::from_sql(bytes)?;
+                Ok(Self::try_from(&index as #return_type_ref)?)
+            }
+        }
+        impl #queryable_impl_generics __private::diesel::deserialize::Queryable<__ST, __DB>
+            for #name #ty_generics #queryable_where_clause
+        {
+            type Row = Self;
+            fn build(row: Self) -> __private::diesel::deserialize::Result<Self> {
+                Ok(row)
+            }
+        }
+        #(#impl_as_expression)*
+    }
+}
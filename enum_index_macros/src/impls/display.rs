@@ -0,0 +1,31 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::structs::{DisplayMode, EnumIndexOptions};
+
+/// Implement [`std::fmt::Display`] using either the index or the variant name.
+///
+/// Variants declared with `#[index(skip)]` have no index, so they are formatted by name
+/// in `"index"` mode, rather than failing.
+pub fn impl_display(input: &DeriveInput, options: &EnumIndexOptions) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match options.display {
+        None => return quote!(),
+        Some(DisplayMode::Index) => quote!(match self.try_index() {
+            Some(index) => write!(f, "{}", index),
+            None => f.write_str(self.variant_name()),
+        }),
+        Some(DisplayMode::Name) => quote!(f.write_str(self.variant_name())),
+    };
+
+    quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #body
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::structs::{EnumIndexOptions, EnumIndexParams, FromStrMode};
+
+/// Implement [`std::str::FromStr`] using `from_index()`, `by_name()` or both.
+///
+/// For `str` index types, the input is looked up directly; otherwise it is parsed into
+/// the index type first.
+pub fn impl_from_str(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let from_index = if params.is_str_like() {
+        quote!(Self::try_from(s))
+    } else {
+        let input_type = params.input_type();
+        quote!(
+            s.parse::<#input_type>()
+            .map_err(|_| EnumIndexError::IndexNotFound(format!("{:?}", s)))
+            .and_then(|index| Self::try_from(&index))
+        )
+    };
+    let from_name =
+        quote!(Self::by_name(s).ok_or_else(|| EnumIndexError::NameNotFound(s.to_string())));
+
+    let body = match options.from_str {
+        None => return quote!(),
+        Some(FromStrMode::Index) => from_index,
+        Some(FromStrMode::Name) => from_name,
+        Some(FromStrMode::Both) => quote!(
+            #from_index.or_else(|err| Self::by_name(s).ok_or(err))
+        ),
+    };
+
+    quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = EnumIndexError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                #body
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// The names of the inherent methods generated for a single mapping.
+pub struct IndexMethodNames {
+    pub index: syn::Ident,
+    pub try_index: syn::Ident,
+    pub from_index: syn::Ident,
+    pub from_index_slice: syn::Ident,
+    pub is_deprecated_index: syn::Ident,
+}
+impl IndexMethodNames {
+    /// `index()`, `try_index()`, `from_index()`, `from_index_slice()` and
+    /// `is_deprecated_index()` for the unnamed or primary mapping; `wire_index()`,
+    /// `try_wire_index()` etc. for a mapping named `wire`.
+    pub fn for_mapping(mapping: Option<&syn::Ident>) -> Self {
+        match mapping {
+            None => Self {
+                index: format_ident!("index"),
+                try_index: format_ident!("try_index"),
+                from_index: format_ident!("from_index"),
+                from_index_slice: format_ident!("from_index_slice"),
+                is_deprecated_index: format_ident!("is_deprecated_index"),
+            },
+            Some(mapping) => Self {
+                index: format_ident!("{}_index", mapping),
+                try_index: format_ident!("try_{}_index", mapping),
+                from_index: format_ident!("from_{}_index", mapping),
+                from_index_slice: format_ident!("from_{}_index_slice", mapping),
+                is_deprecated_index: format_ident!("is_deprecated_{}_index", mapping),
+            },
+        }
+    }
+}
+
+/// Convert an index expression into a value of the return type, such as a `str` literal
+/// into a `String`.
+pub fn index_value(params: &EnumIndexParams, index: &syn::Expr) -> TokenStream {
+    if params.is_type("String") && func::is_lit_str(index) {
+        quote!(#index.to_string())
+    } else if params.is_cow() && func::is_lit_str(index) {
+        quote!(::std::borrow::Cow::Borrowed(#index))
+    } else {
+        quote!(#index)
+    }
+}
+
+/// The scrutinee to match an `index` argument against in `from_index()`.
+///
+/// Dereference the lookup value where possible, so that `const` items can be used as
+/// patterns; `str` literal patterns however have to match against `&str`.
+pub fn index_scrutinee(params: &EnumIndexParams) -> TokenStream {
+    if params.is_str_like() || params.is_ref {
+        quote!(index)
+    } else {
+        quote!(*index)
+    }
+}
+
+/// The bounds of the indices if they form a dense table, that is if they are integer
+/// literals, retired ones included, which cover a contiguous range without gaps.
+fn dense_range(
+    params: &EnumIndexParams,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> Option<(i128, i128)> {
+    const INTEGER_TYPES: [&str; 12] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+    if params.is_ref || !INTEGER_TYPES.iter().any(|ty| params.is_type(ty)) {
+        return None;
+    }
+
+    let mut values = indexed_variants
+        .iter()
+        .flat_map(|(_, variant_index)| {
+            variant_index
+                .expr()
+                .into_iter()
+                .chain(variant_index.deprecated())
+        })
+        .map(enum_index_syntax::eval_int)
+        .collect::<Option<Vec<_>>>()?;
+    values.sort_unstable();
+
+    let is_contiguous = values
+        .windows(2)
+        .all(|pair| pair[0].checked_add(1) == Some(pair[1]));
+    match (values.first(), values.last()) {
+        (Some(&min), Some(&max)) if is_contiguous => Some((min, max)),
+        _ => None,
+    }
+}
+
+/// Build the `index()`, `try_index()`, `from_index()` etc. methods of a single mapping.
+///
+/// These go inside the inherent `impl` block of the enum.
+pub fn impl_index_methods(
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+    names: &IndexMethodNames,
+) -> TokenStream {
+    let return_type = params.return_type_specified();
+    let return_type_ref = params.return_type_ref();
+    let IndexMethodNames {
+        index: fn_index,
+        try_index: fn_try_index,
+        from_index: fn_from_index,
+        from_index_slice: fn_from_index_slice,
+        is_deprecated_index: fn_is_deprecated_index,
+    } = names;
+
+    let (match_to_index, match_try_index, match_from_index) = indexed_variants
+        .iter()
+        .map(
+            // Now that we know we have a index for each variant, lets roll them together
+            // in to a single TokenStream.
+            |(variant, variant_index)| {
+                let variant_pattern = func::variant_pattern(variant);
+                let variant_constructor = func::variant_constructor(variant);
+                let ident_str = variant.ident.to_string();
+
+                match variant_index.expr() {
+                    Some(index) => {
+                        let index_value = index_value(params, index);
+
+                        (
+                            // match_to_index
+                            quote!(
+                                #variant_pattern=>#index_value,
+                            ),
+                            // match_try_index
+                            quote!(
+                                #variant_pattern=>Some(#index_value),
+                            ),
+                            // match_from_index
+                            quote!(
+                                #index=>Some(#variant_constructor),
+                            ),
+                        )
+                    }
+                    // #[index(skip)]: never returned by `from_index`.
+                    None => (
+                        quote!(
+                            #variant_pattern=>panic!(
+                                "{}", EnumIndexError::VariantSkipped(#ident_str.to_string())
+                            ),
+                        ),
+                        quote!(
+                            #variant_pattern=>None,
+                        ),
+                        quote!(),
+                    ),
+                }
+            },
+        )
+        .fold((quote!(), quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs.2.extend(rhs.2);
+            lhs
+        });
+
+    // Retired indices are matched after all the current ones, so that the current
+    // indices take precedence.
+    let (match_from_deprecated, match_is_deprecated) = indexed_variants
+        .iter()
+        .flat_map(|(variant, variant_index)| {
+            let variant_constructor = func::variant_constructor(variant);
+
+            variant_index.deprecated().iter().map(move |index| {
+                (
+                    quote!(
+                        #index=>Some(#variant_constructor),
+                    ),
+                    quote!(
+                        #index=>true,
+                    ),
+                )
+            })
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+    let match_is_current = indexed_variants.iter().filter_map(|(_, variant_index)| {
+        variant_index.expr().map(|index| {
+            quote!(
+                #index=>false,
+            )
+        })
+    });
+
+    let match_from_index_scrutinee = index_scrutinee(params);
+
+    // With #[enum_index(skipped = "option")], `index()` returns `Option<T>` instead.
+    let impl_index = if options.is_index_optional() {
+        quote! {
+            pub fn #fn_index(&self) -> Option<#return_type> {
+                self.#fn_try_index()
+            }
+        }
+    } else {
+        quote! {
+            pub fn #fn_index(&self) -> #return_type {
+                match self {
+                    #match_to_index
+                }
+            }
+        }
+    };
+
+    // For a dense table, the whole slice is checked against the range of indices first,
+    // so that the lookup itself has no early exit and can be vectorized; the match over a
+    // contiguous range is lowered to a lookup table.
+    let from_index_slice = match dense_range(params, indexed_variants) {
+        Some((min, max)) => {
+            let (min, max) = (
+                enum_index_syntax::int_expr(min),
+                enum_index_syntax::int_expr(max),
+            );
+            let match_dense = indexed_variants
+                .iter()
+                .flat_map(|(variant, variant_index)| {
+                    let variant_constructor = func::variant_constructor(variant);
+                    variant_index
+                        .expr()
+                        .into_iter()
+                        .chain(variant_index.deprecated())
+                        .map(move |index| quote!(#index=>#variant_constructor,))
+                });
+            quote! {
+                if let Some(position) = indices
+                    .iter()
+                    .position(|index| !(#min..=#max).contains(index))
+                {
+                    let index = &indices[position];
+                    return Err((position, EnumIndexError::IndexNotFound(format!("{:?}", index))));
+                }
+                Ok(indices
+                    .iter()
+                    .map(|index| match #match_from_index_scrutinee {
+                        #(#match_dense)*
+                        _ => unreachable!(),
+                    })
+                    .collect())
+            }
+        }
+        None => quote! {
+            indices
+                .iter()
+                .enumerate()
+                .map(|(position, index)| {
+                    Self::#fn_from_index(index).ok_or_else(|| {
+                        (position, EnumIndexError::IndexNotFound(format!("{:?}", index)))
+                    })
+                })
+                .collect()
+        },
+    };
+
+    quote! {
+        #impl_index
+        /// Get the index of this variant, or [`None`] if it was declared with
+        /// `#[index(skip)]`.
+        pub fn #fn_try_index(&self) -> Option<#return_type> {
+            match self {
+                #match_try_index
+            }
+        }
+        pub fn #fn_from_index(index: #return_type_ref) -> Option<Self> {
+            match #match_from_index_scrutinee {
+                #match_from_index
+                #match_from_deprecated
+                _ => None
+            }
+        }
+        /// Look up a whole slice of indices, such as a table read from a file.
+        ///
+        /// Fails with the position of the first index that `from_index()` does not accept.
+        pub fn #fn_from_index_slice(
+            indices: &[#return_type],
+        ) -> Result<Vec<Self>, (usize, EnumIndexError)> {
+            #from_index_slice
+        }
+        /// Check if the index is a retired one, which is still accepted by
+        /// `from_index()` but never returned by `index()`.
+        #[allow(unreachable_patterns)]
+        pub fn #fn_is_deprecated_index(index: #return_type_ref) -> bool {
+            match #match_from_index_scrutinee {
+                #(#match_is_current)*
+                #match_is_deprecated
+                _ => false
+            }
+        }
+    }
+}
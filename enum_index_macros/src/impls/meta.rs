@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{IndexMappings, MetaSchema, MetaValues};
+
+use super::IndexMethodNames;
+
+/// The other items of the inherent `impl` block, which an accessor cannot be named after.
+const RESERVED: &[&str] = &[
+    "variant_name",
+    "description",
+    "INDEX_TABLE",
+    "match_prefix",
+    "index_for",
+    "from_index_for",
+    "proto_definition",
+    "typescript_definition",
+    "any_index_strategy",
+];
+
+/// Build an accessor method for every key declared in #[index_meta_type(...)].
+///
+/// These go inside the inherent `impl` block of the enum. Variants that do not state a
+/// value for a key return [`Default::default()`] for it. Keys that are given twice, or
+/// that are named after another generated method, fail with an error at the key.
+pub fn impl_meta_accessors(
+    input: &DeriveInput,
+    mappings: &IndexMappings,
+    variants: &[&syn::Variant],
+) -> TokenStream {
+    // Parse errors are reported at the offending key.
+    let to_compile_error = |err| match err {
+        errors::EnumIndexError::SynParseError(_, err) => err.to_compile_error(),
+        err => panic!("{}", err),
+    };
+
+    let schema = match MetaSchema::try_from(input) {
+        Ok(schema) => schema,
+        Err(err) => return to_compile_error(err),
+    };
+
+    let index_methods = std::iter::once(None)
+        .chain(mappings.iter().map(|mapping| mapping.name.as_ref()))
+        .flat_map(|mapping| {
+            let names = IndexMethodNames::for_mapping(mapping);
+            [
+                names.index,
+                names.try_index,
+                names.from_index,
+                names.from_index_slice,
+                names.is_deprecated_index,
+            ]
+        })
+        .collect::<Vec<_>>();
+    if let Some((key, _)) = schema.iter().find(|(key, _)| {
+        RESERVED.iter().any(|reserved| key == reserved) || index_methods.contains(key)
+    }) {
+        return syn::parse::Error::new(
+            key.span(),
+            format!(
+                "metadata key `{}` clashes with the method of the same name generated by \
+                 #[derive(EnumIndex)]; use another name.",
+                key
+            ),
+        )
+        .to_compile_error();
+    }
+
+    let values = match variants
+        .iter()
+        .map(|variant| MetaValues::find(variant, &schema).map(|values| (variant, values)))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(values) => values,
+        Err(err) => return to_compile_error(err),
+    };
+
+    schema
+        .iter()
+        .map(|(key, t)| {
+            let match_to_value = values.iter().map(|(variant, values)| {
+                let variant_pattern = func::variant_pattern(variant);
+                let value = values
+                    .get(key)
+                    .map(|value| quote!(#value))
+                    .unwrap_or_else(|| quote!(::std::default::Default::default()));
+
+                quote!(
+                    #variant_pattern=>#value,
+                )
+            });
+            let doc = format!("Get the `{}` metadata of this variant.", key);
+
+            quote! {
+                #[doc = #doc]
+                pub fn #key(&self) -> #t {
+                    match self {
+                        #(#match_to_value)*
+                    }
+                }
+            }
+        })
+        .collect()
+}
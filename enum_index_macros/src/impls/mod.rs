@@ -0,0 +1,65 @@
+//! Generated methods and trait implementations, split out of [`crate::enum_index`].
+//!
+//! Most of these are optional, switched on by #[enum_index(...)] options.
+//!
+//! Each function here returns the [`TokenStream`] of a single `impl` block, or of the
+//! methods to go inside one; this is empty if the option was not requested.
+//!
+//! [`TokenStream`]: proc_macro2::TokenStream
+
+mod index;
+pub use index::{impl_index_methods, IndexMethodNames};
+
+mod display;
+pub use display::impl_display;
+
+mod from_str;
+pub use from_str::impl_from_str;
+
+mod meta;
+pub use meta::impl_meta_accessors;
+
+mod description;
+pub use description::impl_descriptions;
+
+mod versions;
+pub use versions::{impl_version_enum, impl_versioned_index_methods};
+
+mod clap;
+pub use clap::impl_value_enum;
+
+mod sqlx;
+pub use sqlx::impl_sqlx;
+
+mod rusqlite;
+pub use rusqlite::impl_rusqlite;
+
+mod diesel;
+pub use diesel::impl_diesel;
+
+mod schemars;
+pub use schemars::impl_schemars;
+
+mod utoipa;
+pub use utoipa::impl_utoipa;
+
+mod prost;
+pub use prost::{impl_prost_conversions, impl_proto_definition};
+
+mod arbitrary;
+pub use arbitrary::impl_arbitrary;
+
+mod proptest;
+pub use proptest::{impl_any_index_strategy, impl_proptest};
+
+mod rkyv;
+pub use rkyv::impl_rkyv;
+
+mod bytemuck;
+pub use bytemuck::impl_checked_bit_pattern;
+
+mod pyo3;
+pub use pyo3::impl_pyo3;
+
+mod wasm;
+pub use wasm::{impl_typescript_definition, impl_wasm};
@@ -0,0 +1,163 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement `proptest::arbitrary::Arbitrary`, if `#[enum_index(proptest = true)]` is
+/// given; this requires the `proptest` feature.
+///
+/// Only variants with an index are generated, so that every generated value survives a
+/// round trip through `index()` and `from_index()`; an Enum whose variants are all
+/// skipped is rejected, as there is no value to generate.
+pub fn impl_proptest(
+    input: &DeriveInput,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.proptest != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "proptest = true",
+        "proptest",
+        cfg!(feature = "proptest"),
+    );
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // `Arbitrary` requires `Debug`.
+    let generics = {
+        let mut generics = input.generics.clone();
+        let where_clause = generics.make_where_clause();
+        where_clause
+            .predicates
+            .push(parse_quote!(#name #ty_generics: ::std::fmt::Debug));
+        where_clause
+            .predicates
+            .push(parse_quote!(#name #ty_generics: 'static));
+        generics
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let constructors = indexed_variants
+        .iter()
+        .filter(|(_, variant_index)| variant_index.expr().is_some())
+        .map(|(variant, _)| func::variant_constructor(variant))
+        .collect::<Vec<_>>();
+    if constructors.is_empty() {
+        panic!(
+            "{}",
+            errors::EnumIndexError::NoIndexToGenerate(
+                input.ident.to_string(),
+                "proptest = true".to_string()
+            )
+        );
+    }
+    let count = constructors.len();
+    let positions = 0..count;
+
+    quote! {
+        impl #impl_generics __private::proptest::arbitrary::Arbitrary for #name #ty_generics
+        #where_clause
+        {
+            type Parameters = ();
+            type Strategy = __private::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                __private::proptest::strategy::Strategy::boxed(
+                    __private::proptest::strategy::Strategy::prop_map(
+                        0..#count,
+                        |position| match position {
+                            #(#positions => #constructors,)*
+                            _ => unreachable!(),
+                        },
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// Build `any_index_strategy()`, if `#[enum_index(proptest = true)]` is given.
+///
+/// The strategy yields valid indices, including retired ones, as often as near misses of
+/// them from [`NearMiss`], which `from_index()` must reject. It goes inside the inherent
+/// `impl` block of the enum. There is always a valid index, as `impl_proptest()` rejects
+/// Enums whose variants are all skipped.
+///
+/// [`NearMiss`]: enum_index_types::NearMiss
+pub fn impl_any_index_strategy(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.proptest != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "proptest = true",
+        "proptest",
+        cfg!(feature = "proptest"),
+    );
+
+    let input_type = params.input_type();
+    let input_type_ref = &input_type;
+
+    let valid = indexed_variants.iter().flat_map(|(_, variant_index)| {
+        variant_index
+            .expr()
+            .into_iter()
+            .chain(variant_index.deprecated())
+            .map(move |index| {
+                // `str` indices are generated as owned `String`s, like parsed input.
+                let index_value = match params.is_str_like() {
+                    true => quote!(<#input_type_ref>::from(#index)),
+                    false => quote!(#index),
+                };
+                quote!(
+                    valid.push(#index_value);
+                )
+            })
+    });
+
+    quote! {
+        /// A `proptest` strategy of indices, half of which are valid, and the other half
+        /// near misses of valid indices which are rejected by `from_index()`.
+        pub fn any_index_strategy(
+        ) -> __private::proptest::strategy::BoxedStrategy<#input_type>
+        where
+            #input_type: NearMiss + Clone + ::std::fmt::Debug + 'static,
+        {
+            let mut valid: Vec<#input_type> = Vec::new();
+            #(#valid)*
+
+            let invalid = valid
+                .iter()
+                .flat_map(NearMiss::near_misses)
+                .filter(|index| Self::from_index(index).is_none())
+                .collect::<Vec<_>>();
+
+            let valid = __private::proptest::sample::select(valid);
+            if invalid.is_empty() {
+                __private::proptest::strategy::Strategy::boxed(valid)
+            } else {
+                __private::proptest::strategy::Strategy::boxed(
+                    __private::proptest::strategy::Union::new([
+                        __private::proptest::strategy::Strategy::boxed(valid),
+                        __private::proptest::strategy::Strategy::boxed(
+                            __private::proptest::sample::select(invalid),
+                        ),
+                    ]),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, RenameRule, VariantIndex};
+
+/// Implement conversions to and from the `prost` generated enum given by
+/// `#[enum_index(prost = "path::to::Enum")]`.
+///
+/// Variants are matched by name, which `prost` keeps in `PascalCase` with the prefix of
+/// the enum removed. Variants declared with `#[index(skip)]` have no counterpart, in which
+/// case the conversion into the `prost` enum becomes fallible as well.
+pub fn impl_prost_conversions(
+    input: &DeriveInput,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    let prost = match &options.prost {
+        Some(prost) => prost,
+        None => return quote!(),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let has_skipped = indexed_variants
+        .iter()
+        .any(|(_, variant_index)| variant_index.expr().is_none());
+
+    let (match_into_prost, match_from_prost) = indexed_variants
+        .iter()
+        .map(|(variant, variant_index)| {
+            let variant_ident = &variant.ident;
+            // `Self` is the `prost` enum in `From<MyEnum>`, so the name is spelt out.
+            let variant_pattern = quote!(#name::#variant_ident { .. });
+            let variant_constructor = func::variant_constructor(variant);
+            let ident_str = variant_ident.to_string();
+
+            match variant_index.expr() {
+                Some(_) if has_skipped => (
+                    quote!(#variant_pattern=>Ok(#prost::#variant_ident),),
+                    quote!(#prost::#variant_ident=>Ok(#variant_constructor),),
+                ),
+                Some(_) => (
+                    quote!(#variant_pattern=>#prost::#variant_ident,),
+                    quote!(#prost::#variant_ident=>Ok(#variant_constructor),),
+                ),
+                None => (
+                    quote!(
+                        #variant_pattern=>Err(
+                            EnumIndexError::VariantSkipped(#ident_str.to_string())
+                        ),
+                    ),
+                    quote!(),
+                ),
+            }
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+
+    let impl_into_prost = if has_skipped {
+        quote! {
+            impl #impl_generics TryFrom<#name #ty_generics> for #prost #where_clause {
+                type Error = EnumIndexError;
+                fn try_from(value: #name #ty_generics) -> Result<Self, Self::Error> {
+                    match value {
+                        #match_into_prost
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics From<#name #ty_generics> for #prost #where_clause {
+                fn from(value: #name #ty_generics) -> Self {
+                    match value {
+                        #match_into_prost
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #impl_into_prost
+        impl #impl_generics TryFrom<#prost> for #name #ty_generics #where_clause {
+            type Error = EnumIndexError;
+            #[allow(unreachable_patterns)]
+            fn try_from(value: #prost) -> Result<Self, Self::Error> {
+                match value {
+                    #match_from_prost
+                    _ => Err(EnumIndexError::NameNotFound(format!("{:?}", value))),
+                }
+            }
+        }
+    }
+}
+
+/// Build `proto_definition()`, if `#[enum_index(proto_definition = true)]` is given.
+///
+/// This returns the definition of a protobuf enum of the same name, with values named in
+/// `SCREAMING_SNAKE_CASE` prefixed by the enum name as per the protobuf style guide.
+/// Retired indices are listed as `reserved`, and doc comments are carried over.
+pub fn impl_proto_definition(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.proto_definition != Some(true) {
+        return quote!();
+    }
+    if !params.is_type("i32") {
+        panic!(
+            "{}",
+            errors::EnumIndexError::ProtoIndexTypeNotI32(input.ident.to_string())
+        );
+    }
+
+    let enum_name = input.ident.to_string();
+    let prefix = RenameRule::ScreamingSnakeCase.apply(&enum_name);
+
+    let literal = |variant: &syn::Variant, index: &syn::Expr| {
+        func::lit_to_string(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                errors::EnumIndexError::IndexNotLiteral(
+                    variant.ident.to_string(),
+                    "#[enum_index(proto_definition = true)]".to_string()
+                )
+            )
+        })
+    };
+    let comment = |doc: Option<String>, indent: &str| {
+        doc.map(|doc| {
+            doc.lines()
+                .map(|line| format!("{}// {}\n", indent, line).replace("// \n", "//\n"))
+                .collect::<String>()
+        })
+        .unwrap_or_default()
+    };
+
+    let values = indexed_variants
+        .iter()
+        .filter_map(|(variant, variant_index)| {
+            variant_index.expr().map(|index| {
+                format!(
+                    "{}  {}_{} = {};\n",
+                    comment(func::doc_comment(&variant.attrs), "  "),
+                    prefix,
+                    RenameRule::ScreamingSnakeCase.apply(&variant.ident.to_string()),
+                    literal(variant, index),
+                )
+            })
+        })
+        .collect::<String>();
+    let reserved = indexed_variants
+        .iter()
+        .flat_map(|(variant, variant_index)| {
+            variant_index
+                .deprecated()
+                .iter()
+                .map(move |index| literal(variant, index))
+        })
+        .collect::<Vec<_>>();
+    let reserved = if reserved.is_empty() {
+        String::new()
+    } else {
+        format!("  reserved {};\n", reserved.join(", "))
+    };
+
+    let definition = format!(
+        "{}enum {} {{\n{}{}}}\n",
+        comment(func::doc_comment(&input.attrs), ""),
+        enum_name,
+        values,
+        reserved,
+    );
+
+    quote! {
+        /// The definition of this enum as a protobuf enum, in `.proto` syntax.
+        pub fn proto_definition() -> &'static str {
+            #definition
+        }
+    }
+}
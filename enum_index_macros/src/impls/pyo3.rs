@@ -0,0 +1,90 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams};
+
+/// Implement `pyo3::IntoPy<PyObject>`, `pyo3::ToPyObject` and `pyo3::FromPyObject`, if
+/// `#[enum_index(pyo3 = true)]` is given; this requires the `pyo3` feature.
+///
+/// Python sees the index; converting an unknown index raises `ValueError` with the
+/// message of the `EnumIndexError`. An index type that pyo3 cannot convert fails to
+/// compile.
+pub fn impl_pyo3(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    if options.pyo3 != Some(true) {
+        return quote!();
+    }
+    func::require_feature(input, "pyo3 = true", "pyo3", cfg!(feature = "pyo3"));
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let return_type = params.return_type_specified();
+    let return_type_ref = params.return_type_ref();
+    let input_type = params.input_type();
+
+    let build_generics = |generics: &syn::Generics, bound: syn::WherePredicate| {
+        let mut generics = generics.clone();
+        generics.make_where_clause().predicates.push(bound);
+        generics
+    };
+    let into_py_generics = build_generics(
+        &input.generics,
+        parse_quote!(
+            #return_type: __private::pyo3::IntoPy<__private::pyo3::PyObject>
+        ),
+    );
+    let to_object_generics = build_generics(
+        &input.generics,
+        parse_quote!(#return_type: __private::pyo3::ToPyObject),
+    );
+    let extract_generics = build_generics(
+        &func::generics_with_lifetime(&input.generics, "'py"),
+        parse_quote!(#input_type: __private::pyo3::FromPyObject<'py>),
+    );
+    let (into_py_impl_generics, _, into_py_where_clause) = into_py_generics.split_for_impl();
+    let (to_object_impl_generics, _, to_object_where_clause) = to_object_generics.split_for_impl();
+    let (extract_impl_generics, _, extract_where_clause) = extract_generics.split_for_impl();
+
+    // Like `index()`, converting a skipped variant panics, which pyo3 raises as a
+    // `PanicException`.
+    let index = quote! {
+        self.try_index().unwrap_or_else(|| {
+            panic!("{}", EnumIndexError::VariantSkipped(self.variant_name().to_string()))
+        })
+    };
+
+    quote! {
+        impl #into_py_impl_generics __private::pyo3::IntoPy<__private::pyo3::PyObject>
+            for #name #ty_generics
+        #into_py_where_clause
+        {
+            fn into_py(self, py: __private::pyo3::Python<'_>) -> __private::pyo3::PyObject {
+                __private::pyo3::IntoPy::into_py(#index, py)
+            }
+        }
+        impl #to_object_impl_generics __private::pyo3::ToPyObject for #name #ty_generics
+        #to_object_where_clause
+        {
+            fn to_object(&self, py: __private::pyo3::Python<'_>) -> __private::pyo3::PyObject {
+                __private::pyo3::ToPyObject::to_object(&#index, py)
+            }
+        }
+        impl #extract_impl_generics __private::pyo3::FromPyObject<'py> for #name #ty_generics
+        #extract_where_clause
+        {
+            fn extract_bound(
+                ob: &__private::pyo3::Bound<'py, __private::pyo3::PyAny>,
+            ) -> __private::pyo3::PyResult<Self> {
+                let index = <#input_type as __private::pyo3::FromPyObject<'py>>::extract_bound(ob)?;
+                Self::try_from(&index as #return_type_ref).map_err(|err| {
+                    __private::pyo3::exceptions::PyValueError::new_err(err.to_string())
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams};
+
+/// Implement `rkyv::Archive`, `rkyv::Serialize` and `rkyv::Deserialize`, if
+/// `#[enum_index(rkyv = true)]` is given; this requires the `rkyv` feature.
+///
+/// The enum is archived as its index, wrapped in a generated `Archived{Enum}` whose
+/// `CheckBytes` also runs the index through `from_index()`, so that validation rejects
+/// unknown indices. `str` indices are archived as `String`.
+///
+/// Enums with type or lifetime parameters are rejected, as the archived type is not
+/// generic.
+pub fn impl_rkyv(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    if options.rkyv != Some(true) {
+        return quote!();
+    }
+    func::require_feature(input, "rkyv = true", "rkyv", cfg!(feature = "rkyv"));
+    if !input.generics.params.is_empty() {
+        panic!(
+            "{}",
+            errors::EnumIndexError::RkyvWithGenerics(input.ident.to_string())
+        );
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let archived_name = format_ident!("Archived{}", name);
+    let input_type = params.input_type();
+    let return_type_ref = params.return_type_ref();
+
+    let archive_bound = quote!(#input_type: __private::rkyv::Archive);
+    let archived_index = quote!(<#input_type as __private::rkyv::Archive>::Archived);
+    // `Self::Archived` and `Self::Resolver` would be ambiguous with variants of the same
+    // names, so these are spelt out.
+    let resolver = quote!(<#input_type as __private::rkyv::Archive>::Resolver);
+    let doc = format!(
+        "An archived [`{}`], stored as its index.\n\nValidating this with `CheckBytes` \
+         fails for any index that `{}::from_index()` does not accept.",
+        name, name,
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[repr(transparent)]
+        #vis struct #archived_name
+        where
+            #archive_bound,
+        {
+            index: #archived_index,
+        }
+        impl #archived_name
+        where
+            #archive_bound,
+        {
+            /// The archived index.
+            pub fn index(&self) -> &#archived_index {
+                &self.index
+            }
+        }
+        impl ::std::fmt::Debug for #archived_name
+        where
+            #archive_bound,
+            #archived_index: ::std::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_tuple(stringify!(#archived_name)).field(&self.index).finish()
+            }
+        }
+        // SAFETY: the archived type is a transparent wrapper of the archived index, which
+        // is itself `Portable`.
+        unsafe impl __private::rkyv::Portable for #archived_name
+        where
+            #archive_bound,
+        {
+        }
+        // SAFETY: the archived index is checked first, and only then read to look it up.
+        unsafe impl<__C> __private::rkyv::bytecheck::CheckBytes<__C> for #archived_name
+        where
+            #archive_bound,
+            __C: __private::rkyv::rancor::Fallible + ?Sized,
+            __C::Error: __private::rkyv::rancor::Source,
+            #archived_index: __private::rkyv::bytecheck::CheckBytes<__C>
+                + __private::rkyv::Deserialize<
+                    #input_type,
+                    __private::rkyv::rancor::Strategy<(), __C::Error>,
+                >,
+        {
+            unsafe fn check_bytes(value: *const Self, context: &mut __C) -> Result<(), __C::Error> {
+                let index = value.cast::<#archived_index>();
+                unsafe {
+                    <#archived_index as __private::rkyv::bytecheck::CheckBytes<__C>>::check_bytes(
+                        index, context,
+                    )?;
+                }
+                let index = __private::rkyv::Deserialize::deserialize(
+                    unsafe { &*index },
+                    __private::rkyv::rancor::Strategy::<(), __C::Error>::wrap(&mut ()),
+                )?;
+                #name::try_from(&index as #return_type_ref)
+                    .map(|_| ())
+                    .map_err(<__C::Error as __private::rkyv::rancor::Source>::new)
+            }
+        }
+        impl __private::rkyv::Archive for #name
+        where
+            #archive_bound,
+        {
+            type Archived = #archived_name;
+            type Resolver = #resolver;
+
+            fn resolve(&self, resolver: #resolver, out: __private::rkyv::Place<#archived_name>) {
+                // Skipped variants have already failed to serialize.
+                let index = <#input_type>::from(self.try_index().unwrap_or_else(|| {
+                    panic!("{}", EnumIndexError::VariantSkipped(self.variant_name().to_string()))
+                }));
+                // SAFETY: the archived type is a transparent wrapper of the archived index.
+                let out = unsafe { out.cast_unchecked::<#archived_index>() };
+                __private::rkyv::Archive::resolve(&index, resolver, out);
+            }
+        }
+        impl<__S> __private::rkyv::Serialize<__S> for #name
+        where
+            #archive_bound,
+            __S: __private::rkyv::rancor::Fallible + ?Sized,
+            __S::Error: __private::rkyv::rancor::Source,
+            #input_type: __private::rkyv::Serialize<__S>,
+        {
+            fn serialize(&self, serializer: &mut __S) -> Result<#resolver, __S::Error> {
+                let index = self.try_index().ok_or_else(|| {
+                    <__S::Error as __private::rkyv::rancor::Source>::new(
+                        EnumIndexError::VariantSkipped(self.variant_name().to_string()),
+                    )
+                })?;
+                __private::rkyv::Serialize::serialize(&<#input_type>::from(index), serializer)
+            }
+        }
+        impl<__D> __private::rkyv::Deserialize<#name, __D> for #archived_name
+        where
+            #archive_bound,
+            __D: __private::rkyv::rancor::Fallible + ?Sized,
+            __D::Error: __private::rkyv::rancor::Source,
+            #archived_index: __private::rkyv::Deserialize<#input_type, __D>,
+        {
+            fn deserialize(&self, deserializer: &mut __D) -> Result<#name, __D::Error> {
+                let index = __private::rkyv::Deserialize::deserialize(&self.index, deserializer)?;
+                #name::try_from(&index as #return_type_ref)
+                    .map_err(<__D::Error as __private::rkyv::rancor::Source>::new)
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams};
+
+/// Implement `rusqlite::ToSql` and `rusqlite::types::FromSql`, if
+/// `#[enum_index(rusqlite = true)]` is given; this requires the `rusqlite` feature.
+///
+/// The index is stored as is; an unknown index fails to decode with
+/// `FromSqlError::Other` carrying an `EnumIndexError`. An index type that rusqlite does
+/// not support fails to compile.
+pub fn impl_rusqlite(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    if options.rusqlite != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "rusqlite = true",
+        "rusqlite",
+        cfg!(feature = "rusqlite"),
+    );
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let input_type = params.input_type();
+    let return_type_ref = params.return_type_ref();
+
+    // `ToSqlOutput` cannot borrow from a `Cow`, so it is stored as an owned `String`.
+    let (to_sql_type, to_sql_output) = if params.is_cow() {
+        (quote!(String), quote!(index.into_owned()))
+    } else {
+        let return_type = params.return_type_specified();
+        (quote!(#return_type), quote!(index))
+    };
+
+    let build_generics = |bound: syn::WherePredicate| {
+        let mut generics = input.generics.clone();
+        generics.make_where_clause().predicates.push(bound);
+        generics
+    };
+    let to_sql_generics = build_generics(parse_quote!(
+        __private::rusqlite::types::ToSqlOutput<'static>: From<#to_sql_type>
+    ));
+    let from_sql_generics = build_generics(parse_quote!(
+        #input_type: __private::rusqlite::types::FromSql
+    ));
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    quote! {
+        impl #to_sql_impl_generics __private::rusqlite::ToSql for #name #ty_generics
+        #to_sql_where_clause
+        {
+            fn to_sql(
+                &self,
+            ) -> __private::rusqlite::Result<__private::rusqlite::types::ToSqlOutput<'_>> {
+                let index = self.try_index().ok_or_else(|| {
+                    __private::rusqlite::Error::ToSqlConversionFailure(Box::new(
+                        EnumIndexError::VariantSkipped(self.variant_name().to_string()),
+                    ))
+                })?;
+                Ok(__private::rusqlite::types::ToSqlOutput::from(#to_sql_output))
+            }
+        }
+        impl #from_sql_impl_generics __private::rusqlite::types::FromSql for #name #ty_generics
+        #from_sql_where_clause
+        {
+            fn column_result(
+                value: __private::rusqlite::types::ValueRef<'_>,
+            ) -> __private::rusqlite::types::FromSqlResult<Self> {
+                let index =
+                    <#input_type as __private::rusqlite::types::FromSql>::column_result(value)?;
+                Self::try_from(&index as #return_type_ref)
+                    .map_err(|err| __private::rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement `schemars::JsonSchema`, if `#[enum_index(schemars = true)]` is given; this
+/// requires the `schemars` feature.
+///
+/// The schema is that of the index type, restricted to the indices of the variants as
+/// serialized by serde. If any variant has a doc comment, the indices are listed as a
+/// `oneOf` of `const` values with their descriptions; otherwise as an `enum`. Variants
+/// declared with `#[index(skip)]` cannot be serialized, hence are left out.
+pub fn impl_schemars(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.schemars != Some(true) {
+        return quote!();
+    }
+    func::require_feature(
+        input,
+        "schemars = true",
+        "schemars",
+        cfg!(feature = "schemars"),
+    );
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let input_type = params.input_type();
+
+    let generics = {
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#input_type: __private::schemars::JsonSchema));
+        generics
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let is_documented = indexed_variants
+        .iter()
+        .any(|(variant, _)| func::doc_comment(&variant.attrs).is_some());
+
+    let push_values = indexed_variants
+        .iter()
+        .filter(|(_, variant_index)| variant_index.expr().is_some())
+        .map(|(variant, _)| {
+            let variant_constructor = func::variant_constructor(variant);
+            let value = quote!(
+                __private::serde_json::to_value(#variant_constructor.try_index()).unwrap()
+            );
+
+            match (is_documented, func::doc_comment(&variant.attrs)) {
+                (false, _) => quote!(values.push(#value);),
+                (true, Some(description)) => quote!(
+                    values.push(__private::serde_json::json!({
+                        "const": #value,
+                        "description": #description,
+                    }));
+                ),
+                (true, None) => quote!(
+                    values.push(__private::serde_json::json!({ "const": #value }));
+                ),
+            }
+        });
+
+    let values_key = if is_documented { "oneOf" } else { "enum" };
+    let insert_description = func::doc_comment(&input.attrs).map(|description| {
+        quote!(
+            schema.insert("description".into(), #description.into());
+        )
+    });
+
+    quote! {
+        impl #impl_generics __private::schemars::JsonSchema for #name #ty_generics #where_clause {
+            fn schema_name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#name_str)
+            }
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(concat!(module_path!(), "::", #name_str))
+            }
+            fn json_schema(
+                generator: &mut __private::schemars::SchemaGenerator,
+            ) -> __private::schemars::Schema {
+                let mut values = Vec::new();
+                #(#push_values)*
+
+                let mut schema =
+                    <#input_type as __private::schemars::JsonSchema>::json_schema(generator);
+                schema.insert(#values_key.into(), __private::serde_json::Value::Array(values));
+                #insert_description
+                schema
+            }
+        }
+    }
+}
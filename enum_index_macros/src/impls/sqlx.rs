@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams};
+
+/// Implement `sqlx::Type`, `sqlx::Encode` and `sqlx::Decode`, if
+/// `#[enum_index(sqlx = true)]` is given; this requires the `sqlx` feature.
+///
+/// These are implemented for any database where the index type implements the same
+/// traits. `str` indices are encoded as `&str` or `String`, and decoded as `String`.
+pub fn impl_sqlx(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    if options.sqlx != Some(true) {
+        return quote!();
+    }
+    func::require_feature(input, "sqlx = true", "sqlx", cfg!(feature = "sqlx"));
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let input_type = params.input_type();
+    let return_type_ref = params.return_type_ref();
+
+    // `&'static str` is shortened to the `'q` of the query, for which `Encode` is
+    // implemented; `Cow` is encoded as an owned `String`.
+    let (encode_type, encode_value) = if params.is_static_str() {
+        (quote!(&'q str), quote!(index))
+    } else if params.is_cow() {
+        (quote!(String), quote!(index.into_owned()))
+    } else {
+        let return_type = params.return_type_specified();
+        (quote!(#return_type), quote!(index))
+    };
+
+    let build_generics = |lifetime: &str, bound: syn::WherePredicate| {
+        let mut generics = func::generics_with_lifetime(&input.generics, lifetime);
+        generics
+            .params
+            .push(parse_quote!(__DB: __private::sqlx::Database));
+        generics.make_where_clause().predicates.push(bound);
+        generics
+    };
+
+    let type_generics = {
+        let mut generics = input.generics.clone();
+        generics
+            .params
+            .push(parse_quote!(__DB: __private::sqlx::Database));
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#input_type: __private::sqlx::Type<__DB>));
+        generics
+    };
+    let encode_generics = build_generics(
+        "'q",
+        parse_quote!(#encode_type: __private::sqlx::Encode<'q, __DB>),
+    );
+    let decode_generics = build_generics(
+        "'r",
+        parse_quote!(#input_type: __private::sqlx::Decode<'r, __DB>),
+    );
+
+    let (type_impl_generics, _, type_where_clause) = type_generics.split_for_impl();
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+
+    quote! {
+        impl #type_impl_generics __private::sqlx::Type<__DB> for #name #ty_generics
+        #type_where_clause
+        {
+            fn type_info() -> <__DB as __private::sqlx::Database>::TypeInfo {
+                <#input_type as __private::sqlx::Type<__DB>>::type_info()
+            }
+            fn compatible(ty: &<__DB as __private::sqlx::Database>::TypeInfo) -> bool {
+                <#input_type as __private::sqlx::Type<__DB>>::compatible(ty)
+            }
+        }
+        impl #encode_impl_generics __private::sqlx::Encode<'q, __DB> for #name #ty_generics
+        #encode_where_clause
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as __private::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> Result<__private::sqlx::encode::IsNull, __private::sqlx::error::BoxDynError> {
+                let index = self.try_index().ok_or_else(
+                    || EnumIndexError::VariantSkipped(self.variant_name().to_string())
+                )?;
+                let index: #encode_type = #encode_value;
+                <#encode_type as __private::sqlx::Encode<'q, __DB>>::encode_by_ref(&index, buf)
+            }
+        }
+        impl #decode_impl_generics __private::sqlx::Decode<'r, __DB> for #name #ty_generics
+        #decode_where_clause
+        {
+            fn decode(
+                value: <__DB as __private::sqlx::Database>::ValueRef<'r>,
+            ) -> Result<Self, __private::sqlx::error::BoxDynError> {
+                let index =
+                    <#input_type as __private::sqlx::Decode<'r, __DB>>::decode(value)?;
+                Ok(Self::try_from(&index as #return_type_ref)?)
+            }
+        }
+    }
+}
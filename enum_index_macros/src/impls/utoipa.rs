@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput};
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement `utoipa::PartialSchema` and `utoipa::ToSchema`, if
+/// `#[enum_index(utoipa = true)]` is given; this requires the `utoipa` feature.
+///
+/// The schema is that of the index type, with the indices of the variants as serialized
+/// by serde as its `enum` values. Variants declared with `#[index(skip)]` cannot be
+/// serialized, hence are left out. If the schema of the index type is not an object
+/// schema, as for `Option<T>`, it is wrapped in an `allOf` together with the `enum` values.
+pub fn impl_utoipa(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.utoipa != Some(true) {
+        return quote!();
+    }
+    func::require_feature(input, "utoipa = true", "utoipa", cfg!(feature = "utoipa"));
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let input_type = params.input_type();
+
+    let generics = {
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#input_type: __private::utoipa::PartialSchema));
+        generics
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let push_values = indexed_variants
+        .iter()
+        .filter(|(_, variant_index)| variant_index.expr().is_some())
+        .map(|(variant, _)| {
+            let variant_constructor = func::variant_constructor(variant);
+            quote!(
+                values.push(
+                    __private::serde_json::to_value(#variant_constructor.try_index()).unwrap()
+                );
+            )
+        });
+
+    let description = match func::doc_comment(&input.attrs) {
+        Some(description) => quote!(Some(#description.to_string())),
+        None => quote!(None),
+    };
+
+    quote! {
+        impl #impl_generics __private::utoipa::PartialSchema for #name #ty_generics #where_clause {
+            fn schema() -> __private::utoipa::openapi::RefOr<
+                __private::utoipa::openapi::schema::Schema,
+            > {
+                let mut values = Vec::new();
+                #(#push_values)*
+
+                match <#input_type as __private::utoipa::PartialSchema>::schema() {
+                    __private::utoipa::openapi::RefOr::T(
+                        __private::utoipa::openapi::schema::Schema::Object(mut object),
+                    ) => {
+                        object.enum_values = Some(values);
+                        if let Some(description) = #description {
+                            object.description = Some(description);
+                        }
+                        __private::utoipa::openapi::RefOr::T(
+                            __private::utoipa::openapi::schema::Schema::Object(object),
+                        )
+                    }
+                    schema => {
+                        let mut values_object = __private::utoipa::openapi::Object::with_type(
+                            __private::utoipa::openapi::schema::SchemaType::AnyValue,
+                        );
+                        values_object.enum_values = Some(values);
+                        let mut all_of = __private::utoipa::openapi::schema::AllOf::new();
+                        all_of.items = vec![
+                            schema,
+                            __private::utoipa::openapi::RefOr::T(
+                                __private::utoipa::openapi::schema::Schema::Object(values_object),
+                            ),
+                        ];
+                        all_of.description = #description;
+                        __private::utoipa::openapi::RefOr::T(
+                            __private::utoipa::openapi::schema::Schema::AllOf(all_of),
+                        )
+                    }
+                }
+            }
+        }
+        impl #impl_generics __private::utoipa::ToSchema for #name #ty_generics #where_clause {
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(#name_str)
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::func;
+use crate::structs::{EnumIndexParams, IndexVersions, VariantIndex};
+
+use super::index::{index_scrutinee, index_value};
+
+/// Build the `{Enum}Version` enum declared by #[index_versions(...)].
+///
+/// This goes alongside the enum itself, with the same visibility.
+pub fn impl_version_enum(input: &DeriveInput, versions: &IndexVersions) -> TokenStream {
+    let vis = &input.vis;
+    let version_name = IndexVersions::type_name(input);
+    let version_idents = versions.iter().collect::<Vec<_>>();
+    let doc = format!(
+        "The versions of the indices of [`{}`], in ascending order.",
+        input.ident
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis enum #version_name {
+            #(#version_idents,)*
+        }
+        impl #version_name {
+            /// All the versions, in ascending order.
+            pub const ALL: &'static [Self] = &[#(Self::#version_idents,)*];
+        }
+    }
+}
+
+/// Build the `index_for()` and `from_index_for()` methods.
+///
+/// `versioned_variants` holds the resolved variants of each version, in the same order as
+/// `versions`. These go inside the inherent `impl` block of the enum.
+pub fn impl_versioned_index_methods(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    versions: &IndexVersions,
+    versioned_variants: &[Vec<(&syn::Variant, VariantIndex)>],
+) -> TokenStream {
+    let version_name = IndexVersions::type_name(input);
+    let return_type = params.return_type_specified();
+    let return_type_ref = params.return_type_ref();
+    let scrutinee = index_scrutinee(params);
+
+    let (match_index_for, match_from_index_for) = versions
+        .iter()
+        .zip(versioned_variants)
+        .map(|(version, indexed_variants)| {
+            let (to_index, from_index) = indexed_variants
+                .iter()
+                .map(|(variant, variant_index)| {
+                    let variant_pattern = func::variant_pattern(variant);
+                    let variant_constructor = func::variant_constructor(variant);
+
+                    match variant_index.expr() {
+                        Some(index) => {
+                            let index_value = index_value(params, index);
+                            (
+                                quote!(#variant_pattern=>Some(#index_value),),
+                                quote!(#index=>Some(#variant_constructor),),
+                            )
+                        }
+                        // Not present in this version.
+                        None => (quote!(#variant_pattern=>None,), quote!()),
+                    }
+                })
+                .fold((quote!(), quote!()), |mut lhs, rhs| {
+                    lhs.0.extend(rhs.0);
+                    lhs.1.extend(rhs.1);
+                    lhs
+                });
+
+            // Retired indices are matched after all the current ones, as in `from_index()`.
+            let from_deprecated = indexed_variants
+                .iter()
+                .flat_map(|(variant, variant_index)| {
+                    let variant_constructor = func::variant_constructor(variant);
+
+                    variant_index
+                        .deprecated()
+                        .iter()
+                        .map(move |index| quote!(#index=>Some(#variant_constructor),))
+                })
+                .collect::<TokenStream>();
+
+            (
+                quote! {
+                    #version_name::#version => match self {
+                        #to_index
+                    },
+                },
+                quote! {
+                    #version_name::#version => match #scrutinee {
+                        #from_index
+                        #from_deprecated
+                        _ => None
+                    },
+                },
+            )
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+
+    quote! {
+        /// Get the index of this variant in a version, or [`None`] if the variant is not
+        /// present in that version.
+        pub fn index_for(&self, version: #version_name) -> Option<#return_type> {
+            match version {
+                #match_index_for
+            }
+        }
+        /// Find the variant of an index in a version, rejecting variants that are not
+        /// present in that version. Retired indices from `#[index_deprecated(...)]` are
+        /// accepted in the versions where their variant is present.
+        pub fn from_index_for(version: #version_name, index: #return_type_ref) -> Option<Self> {
+            match version {
+                #match_from_index_for
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse_quote, DeriveInput};
+
+use enum_index_types::errors;
+
+use crate::func;
+use crate::structs::{EnumIndexOptions, EnumIndexParams, VariantIndex};
+
+/// Implement the `wasm_bindgen` ABI traits and `From<MyEnum> for JsValue`, if
+/// `#[enum_index(wasm = true)]` is given; this requires the `wasm` feature.
+///
+/// JavaScript sees the index, described as the index type; `str` indices are passed as
+/// `String`. An unknown index coming from JavaScript throws an `Error` with the message
+/// of the `EnumIndexError`. An index type that `wasm_bindgen` cannot pass fails to
+/// compile.
+pub fn impl_wasm(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+) -> TokenStream {
+    if options.wasm != Some(true) {
+        return quote!();
+    }
+    func::require_feature(input, "wasm = true", "wasm", cfg!(feature = "wasm"));
+
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let return_type_ref = params.return_type_ref();
+    let input_type = params.input_type();
+
+    let build_generics = |bound: syn::WherePredicate| {
+        let mut generics = input.generics.clone();
+        generics.make_where_clause().predicates.push(bound);
+        generics
+    };
+    let describe_generics = build_generics(parse_quote!(
+        #input_type: __private::wasm_bindgen::describe::WasmDescribe
+    ));
+    let into_abi_generics = build_generics(parse_quote!(
+        #input_type: __private::wasm_bindgen::convert::OptionIntoWasmAbi
+    ));
+    let from_abi_generics = build_generics(parse_quote!(
+        #input_type: __private::wasm_bindgen::convert::OptionFromWasmAbi
+    ));
+    let js_value_generics = build_generics(parse_quote!(
+        __private::wasm_bindgen::JsValue: From<#input_type>
+    ));
+    let (describe_impl_generics, _, describe_where_clause) = describe_generics.split_for_impl();
+    let (into_abi_impl_generics, _, into_abi_where_clause) = into_abi_generics.split_for_impl();
+    let (from_abi_impl_generics, _, from_abi_where_clause) = from_abi_generics.split_for_impl();
+    let (js_value_impl_generics, _, js_value_where_clause) = js_value_generics.split_for_impl();
+
+    // Like `index()`, passing a skipped variant panics.
+    let index = |value: TokenStream| {
+        quote! {
+            <#input_type>::from(#value.try_index().unwrap_or_else(|| {
+                panic!("{}", EnumIndexError::VariantSkipped(#value.variant_name().to_string()))
+            }))
+        }
+    };
+    let index_of_self = index(quote!(self));
+    let index_of_value = index(quote!(value));
+
+    let into_abi_type = quote!(<#input_type as __private::wasm_bindgen::convert::IntoWasmAbi>::Abi);
+    let from_abi_type = quote!(<#input_type as __private::wasm_bindgen::convert::FromWasmAbi>::Abi);
+
+    quote! {
+        impl #describe_impl_generics __private::wasm_bindgen::describe::WasmDescribe
+            for #name #ty_generics
+        #describe_where_clause
+        {
+            fn describe() {
+                <#input_type as __private::wasm_bindgen::describe::WasmDescribe>::describe()
+            }
+        }
+        impl #into_abi_impl_generics __private::wasm_bindgen::convert::IntoWasmAbi
+            for #name #ty_generics
+        #into_abi_where_clause
+        {
+            type Abi = #into_abi_type;
+
+            fn into_abi(self) -> #into_abi_type {
+                __private::wasm_bindgen::convert::IntoWasmAbi::into_abi(#index_of_self)
+            }
+        }
+        impl #into_abi_impl_generics __private::wasm_bindgen::convert::OptionIntoWasmAbi
+            for #name #ty_generics
+        #into_abi_where_clause
+        {
+            fn none() -> #into_abi_type {
+                <#input_type as __private::wasm_bindgen::convert::OptionIntoWasmAbi>::none()
+            }
+        }
+        impl #from_abi_impl_generics __private::wasm_bindgen::convert::FromWasmAbi
+            for #name #ty_generics
+        #from_abi_where_clause
+        {
+            type Abi = #from_abi_type;
+
+            unsafe fn from_abi(js: #from_abi_type) -> Self {
+                let index =
+                    <#input_type as __private::wasm_bindgen::convert::FromWasmAbi>::from_abi(js);
+                Self::try_from(&index as #return_type_ref)
+                    .unwrap_or_else(|err| __private::wasm_bindgen::throw_str(&err.to_string()))
+            }
+        }
+        impl #from_abi_impl_generics __private::wasm_bindgen::convert::OptionFromWasmAbi
+            for #name #ty_generics
+        #from_abi_where_clause
+        {
+            fn is_none(abi: &#from_abi_type) -> bool {
+                <#input_type as __private::wasm_bindgen::convert::OptionFromWasmAbi>::is_none(abi)
+            }
+        }
+        impl #js_value_impl_generics From<#name #ty_generics> for __private::wasm_bindgen::JsValue
+        #js_value_where_clause
+        {
+            fn from(value: #name #ty_generics) -> Self {
+                __private::wasm_bindgen::JsValue::from(#index_of_value)
+            }
+        }
+    }
+}
+
+/// Build `typescript_definition()`, if `#[enum_index(typescript_definition = true)]` is
+/// given.
+///
+/// This returns a `const` object of the variant names and their indices, along with a
+/// type of the same name for the union of the indices. Doc comments are carried over as
+/// JSDoc; retired indices and skipped variants are left out, as they are never returned.
+pub fn impl_typescript_definition(
+    input: &DeriveInput,
+    params: &EnumIndexParams,
+    options: &EnumIndexOptions,
+    indexed_variants: &[(&syn::Variant, VariantIndex)],
+) -> TokenStream {
+    if options.typescript_definition != Some(true) {
+        return quote!();
+    }
+
+    let enum_name = input.ident.unraw().to_string();
+
+    // 64 and 128 bit integers are passed by `wasm_bindgen` as a `BigInt`.
+    let is_string = params.is_str_like() || params.is_type("char");
+    let is_bigint = ["i64", "u64", "i128", "u128"]
+        .iter()
+        .any(|ty| params.is_type(ty));
+    let literal = |variant: &syn::Variant, index: &syn::Expr| {
+        let value = func::lit_to_string(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                errors::EnumIndexError::IndexNotLiteral(
+                    variant.ident.to_string(),
+                    "#[enum_index(typescript_definition = true)]".to_string()
+                )
+            )
+        });
+        if is_string {
+            string_literal(&value)
+        } else if is_bigint {
+            format!("{}n", value)
+        } else {
+            value
+        }
+    };
+
+    let values = indexed_variants
+        .iter()
+        .filter_map(|(variant, variant_index)| {
+            variant_index.expr().map(|index| {
+                format!(
+                    "{}  {}: {},\n",
+                    comment(func::doc_comment(&variant.attrs), "  "),
+                    variant.ident.unraw(),
+                    literal(variant, index),
+                )
+            })
+        })
+        .collect::<String>();
+
+    let definition = format!(
+        "{comment}export const {name} = {{\n{values}}} as const;\n\
+         {comment}export type {name} = (typeof {name})[keyof typeof {name}];\n",
+        comment = comment(func::doc_comment(&input.attrs), ""),
+        name = enum_name,
+        values = values,
+    );
+
+    quote! {
+        /// The definition of this enum in TypeScript, as a `const` object of its indices
+        /// and a union type of the same name.
+        pub fn typescript_definition() -> &'static str {
+            #definition
+        }
+    }
+}
+
+/// Format a doc comment as JSDoc, on a line of its own.
+fn comment(doc: Option<String>, indent: &str) -> String {
+    doc.map(|doc| {
+        let doc = doc.replace("*/", "*\\/");
+        if doc.contains('\n') {
+            let lines = doc
+                .lines()
+                .map(|line| format!("{} * {}\n", indent, line).replace(" * \n", " *\n"))
+                .collect::<String>();
+            format!("{}/**\n{}{} */\n", indent, lines, indent)
+        } else {
+            format!("{}/** {} */\n", indent, doc)
+        }
+    })
+    .unwrap_or_default()
+}
+
+/// Quote a string as a TypeScript string literal.
+fn string_literal(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c if c.is_control() => format!("\\u{{{:x}}}", c as u32),
+            c => c.to_string(),
+        })
+        .collect::<String>();
+
+    format!("\"{}\"", escaped)
+}
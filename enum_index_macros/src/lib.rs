@@ -12,12 +12,11 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn;
 use syn::{parse_macro_input, DeriveInput};
 
 mod func;
+mod impls;
 mod structs;
-use structs::IndexAttribute;
 
 /// A simple derive macro to map static indexs of a single, consistent type to Rust enum
 /// variants.
@@ -89,8 +88,208 @@ use structs::IndexAttribute;
 /// `String`. `&str` is supported by `String`; simply declare the type as `String` and use
 /// `str` literals for indexs.
 ///
+/// To avoid allocating a `String` on every call to `index()`, declare the type as
+/// `&'static str` or `Cow<'static, str>` instead. `From<MyEnum> for String` is still
+/// implemented for both.
+///
+/// If every index of a `str` type is a literal, a `match_prefix(input: &str)` method is
+/// also generated, returning the variant with the longest index that `input` starts with,
+/// along with the length of that index. This is expanded into a trie of nested `match`
+/// blocks, hence is suitable for tokenizers.
+///
+/// Optional trait implementations can be requested with `#[enum_index(...)]`:
+///
+/// - `display = "index" | "name"` implements [`Display`] using `index()` or the variant
+///   name respectively. Variants declared with `#[index(skip)]` are displayed by name in
+///   `"index"` mode.
+/// - `from_str = "index" | "name" | "both"` implements [`FromStr`] using
+///   `from_index()`, `by_name()`, or the index followed by the name. Non-`str` indices
+///   are parsed into the index type first. `FromStr::Err` is `EnumIndexError`.
+///
+/// - `skipped = "panic" | "option"` chooses what `index()` does for variants declared
+///   with `#[index(skip)]`; see below.
+/// - `primary = "name"` chooses which of multiple named mappings drives `index()`,
+///   [`From`], [`TryFrom`] and serde; see below.
+/// - `auto = "discriminant" | "position" | "name"` fills in any missing `#[index(...)]`
+///   from the `= N` discriminant of the variant, its ordinal position, or its name as a
+///   `str` literal respectively. Positions are calculated as `start + position * step`,
+///   which default to `0` and `1`, e.g. `#[enum_index(auto = "position", start = 1)]`.
+/// - `clap = "index" | "name"` implements `clap::ValueEnum` with the `clap` feature, so
+///   that the enum can be used as the value of a command line argument; the enum must
+///   also derive [`Clone`]. The possible values are the indices or the variant names
+///   respectively, and the doc comments of the variants become their help text. In
+///   `"index"` mode every index must be a literal; retired indices are accepted as
+///   aliases.
+/// - `diesel(sql_type = SmallInt)` implements `ToSql`, `FromSql`, `AsExpression` and
+///   `FromSqlRow` of `diesel` for that SQL type with the `diesel` feature, so that the
+///   enum can be used as a column type directly. Every index must be a constant, and
+///   `str` indices must be literals.
+/// - `prost = "path::to::Enum"` implements conversions to and from an enum generated by
+///   `prost`, matching variants by name; see below.
+/// - `proto_definition = true` generates `proto_definition() -> &'static str`, the
+///   definition of the enum in `.proto` syntax; see below.
+/// - `bytemuck = true` implements `bytemuck::CheckedBitPattern` with the `bytemuck`
+///   feature, so that a buffer of indices can be cast into a slice of the enum without
+///   copying. The enum must be fieldless, derive [`Copy`] and be `#[repr(T)]` of its
+///   index type, with each discriminant equal to its index; this is checked at compile
+///   time. Only current indices are valid bit patterns: unlike `from_index()`, retired
+///   indices are rejected, as they are not the discriminant of any variant.
+/// - `typescript_definition = true` generates `typescript_definition() -> &'static str`,
+///   the definition of the enum in TypeScript; see below.
+/// - `sqlx`, `rusqlite`, `schemars`, `utoipa`, `arbitrary`, `proptest`, `rkyv`, `pyo3`
+///   or `wasm` `= true` implements the traits of the crate of that name, with the feature
+///   of the same name; see below. These are opt-in for each enum, so that a feature
+///   enabled anywhere in the dependency graph does not add trait implementations to
+///   enums that did not ask for them.
+/// - `rename_all = "kebab-case"` chooses how variant names are converted for `clap`; one
+///   of `"verbatim"`, `"lowercase"`, `"UPPERCASE"`, `"camelCase"`, `"PascalCase"`,
+///   `"snake_case"`, `"SCREAMING_SNAKE_CASE"` or `"kebab-case"`, the default.
+///
+/// Options that rely on a feature, such as `clap`, fail to compile with
+/// `EnumIndexError::FeatureNotEnabled` if that feature is not enabled, rather than
+/// silently generating nothing.
+///
+/// [`Display`]: std::fmt::Display
+/// [`FromStr`]: std::str::FromStr
+///
 /// Complex types such as `Option<usize>` will need to be aliased in `#[index_type(T)]`.
-#[proc_macro_derive(EnumIndex, attributes(index_type, index))]
+///
+/// Enums with type or lifetime parameters are supported; the generics are carried over to
+/// every generated `impl`. Variants with fields, such as a `PhantomData<T>` marker, are
+/// constructed by `from_index()` with [`Default::default()`] for each field.
+///
+/// Variants without an index, such as `__NonExhaustive`, can be declared with
+/// `#[index(skip)]`. These are never returned by `from_index()`, and `try_index()`
+/// returns [`None`] for them; `variant_name()` still returns their name. By default
+/// `index()` panics on a skipped variant; with `#[enum_index(skipped = "option")]`,
+/// `index()` returns `Option<T>` instead, and `From<MyEnum> for T` is replaced by
+/// `TryFrom<MyEnum> for T`.
+///
+/// Multiple independent mappings can be declared by naming them, such as
+/// `#[index_type(wire = u16, db = String)]` with `#[index(wire = 3, db = "cancelled")]`
+/// on each variant. Each mapping is given its own `wire_index()`, `try_wire_index()` and
+/// `from_wire_index()` methods; a variant can be left out of a single mapping with
+/// `#[index(db = skip)]`. The first mapping, or the one named by
+/// `#[enum_index(primary = "db")]`, also provides `index()`, [`From`], [`TryFrom`] and
+/// serde as above.
+///
+/// Retired indices that should still be decoded can be declared with
+/// `#[index_deprecated(7, 8)]` alongside the current `#[index(3)]`, or with
+/// `#[index(7, deprecated)]` if the variant has no current index at all. These are
+/// accepted by `from_index()`, [`TryFrom`] and serde, but never returned by `index()`;
+/// `is_deprecated_index(&T)` tells whether an index is a retired one, e.g. for logging.
+///
+/// A whole table of indices can be looked up with `from_index_slice(&[T])`, which fails
+/// with the position of the first unknown index along with the `EnumIndexError`. If the
+/// indices are integer literals covering a contiguous range, retired ones included, the
+/// slice is range-checked in a single pass before the lookup, which can then be vectorized.
+///
+/// Indices that change between versions of a protocol can be declared with
+/// `#[index_versions(v1, v2)]` on the enum, in ascending order, and
+/// `#[index(v1 = 3, v2 = 30)]` on each variant. A value carries over to later versions
+/// until it is changed, and a plain `#[index(3)]` applies to every version. Variants
+/// can be limited to a range of versions with `#[index(since = v2)]` and
+/// `#[index(until = v1)]`, both inclusive. This generates a `MyEnumVersion` enum, along
+/// with `index_for(MyEnumVersion)` and `from_index_for(MyEnumVersion, &T)` which return
+/// [`None`] for variants not present in that version. `index()` and `from_index()`
+/// follow the latest version, hence a variant retired with `until` before the latest
+/// version is treated as if it was declared with `#[index(skip)]`: `index()` panics for
+/// it, unless `#[enum_index(skipped = "option")]` is given. Retired indices declared
+/// with `#[index_deprecated(...)]` are accepted by `from_index_for()` only in the
+/// versions where the variant is present. `#[index_versions(...)]` cannot be combined
+/// with `#[enum_index(auto = "...")]`.
+///
+/// Enums generated by `prost` keep the names of their variants in `PascalCase`, with the
+/// prefix of the enum name removed. With `#[enum_index(prost = "pb::Status")]`,
+/// `TryFrom<pb::Status>` is implemented by matching the variants of the same name, along
+/// with `From<MyEnum> for pb::Status`, or `TryFrom` if any variant is skipped.
+/// Conversely, `#[enum_index(proto_definition = true)]` on an `#[index_type(i32)]` enum
+/// generates the `.proto` definition of a matching enum, with its values named as
+/// `MY_ENUM_VARIANT` and retired indices declared as `reserved`. Every index must be a
+/// literal for this; note that protobuf 3 requires the first value to be `0`.
+///
+/// Static metadata can be attached to each variant with `#[index_meta(...)]`, such as
+/// `#[index_meta(label = "Closed", color = 0xFF0000, deprecated = true)]`. The type of
+/// each key is declared once on the enum with
+/// `#[index_meta_type(label = &'static str, color = u32, deprecated = bool)]`, and an
+/// accessor such as `fn label(&self) -> &'static str` is generated for each key.
+/// Variants that leave out a key return [`Default::default()`] for it. A key may appear
+/// only once per attribute, and must not share its name with a method generated by the
+/// derive, such as `description` or `index`.
+///
+/// The `///` doc comments of each variant are available through
+/// `description(&self) -> Option<&'static str>`. If every index is a constant expression,
+/// an `INDEX_TABLE` constant listing the `(name, index, description)` of every variant is
+/// also generated.
+///
+/// With `#[enum_index(sqlx = true)]` and the `sqlx` feature, `sqlx::Type`,
+/// `sqlx::Encode` and `sqlx::Decode` are implemented through the index, for any
+/// database that supports the index type. An unknown index fails to decode with
+/// `EnumIndexError::IndexNotFound`.
+///
+/// With `#[enum_index(schemars = true)]` and the `schemars` feature,
+/// `schemars::JsonSchema` is implemented as the schema of the index type, restricted to
+/// the indices as serialized by serde; the doc comments of the variants become the
+/// descriptions of their indices. Likewise with `#[enum_index(utoipa = true)]` and the
+/// `utoipa` feature, `utoipa::PartialSchema` and `utoipa::ToSchema` are implemented as
+/// the schema of the index type, with the indices as its `enum` values.
+///
+/// For fuzzing, `#[enum_index(arbitrary = true)]` and `#[enum_index(proptest = true)]`
+/// implement `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` respectively
+/// with the features of the same name, generating any variant with an index; both
+/// reject an Enum whose variants are all skipped. The latter also generates
+/// `any_index_strategy()`, a strategy of indices that are either valid or a near miss of
+/// a valid one, such as an off-by-one integer or a string in a different case; see
+/// `NearMiss`, which is only exported with the `proptest` feature.
+///
+/// With `#[enum_index(pyo3 = true)]` and the `pyo3` feature, `pyo3::IntoPy<PyObject>`
+/// and `pyo3::ToPyObject` convert the enum into its index, and `pyo3::FromPyObject`
+/// extracts the index and looks it up, so that Python code only ever sees the indices.
+/// An unknown index raises `ValueError` with the message of the `EnumIndexError`.
+///
+/// With `#[enum_index(wasm = true)]` and the `wasm` feature, the ABI traits of
+/// `wasm_bindgen` are implemented through the index, so that the enum can be passed to
+/// and from JavaScript as a function argument or return value, and `From<MyEnum> for
+/// JsValue` is implemented likewise. `str` indices are passed as `String`; an unknown
+/// index from JavaScript throws an `Error` with the message of the `EnumIndexError`. To
+/// declare the type on the TypeScript side, `#[enum_index(typescript_definition =
+/// true)]` generates `typescript_definition() -> &'static str`, such as
+///
+/// ```text
+/// export const MyEnum = {
+///   Open: 1,
+///   Closed: 3,
+/// } as const;
+/// export type MyEnum = (typeof MyEnum)[keyof typeof MyEnum];
+/// ```
+///
+/// with doc comments carried over as JSDoc. Every index must be a literal for this;
+/// 64 and 128 bit integers are written as `BigInt` literals, as `wasm_bindgen` passes
+/// them.
+///
+/// With `#[enum_index(rkyv = true)]` and the `rkyv` feature, `rkyv::Archive`,
+/// `rkyv::Serialize` and `rkyv::Deserialize` are implemented by archiving the index, as
+/// `String` for `str` indices. The archived type is a generated `ArchivedMyEnum`, whose
+/// `CheckBytes` looks the index up with `from_index()`, so that validating archived
+/// data with an unknown index fails rather than producing an invalid enum. Enums with
+/// generics are rejected.
+///
+/// Similarly with `#[enum_index(rusqlite = true)]` and the `rusqlite` feature,
+/// `rusqlite::ToSql` and `rusqlite::types::FromSql` are implemented through the index,
+/// if rusqlite supports the index type; an unknown index fails with
+/// `FromSqlError::Other`, carrying the `EnumIndexError`.
+#[proc_macro_derive(
+    EnumIndex,
+    attributes(
+        index_type,
+        index,
+        index_deprecated,
+        index_versions,
+        enum_index,
+        index_meta_type,
+        index_meta
+    )
+)]
 pub fn enum_index(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
@@ -100,8 +299,8 @@ pub fn enum_index(input: TokenStream) -> TokenStream {
 
     // Find the `#[index_type(T)]` supplied to us.
     // Let it panic if there is one.
-    let params = {
-        structs::EnumIndexParams::try_from(&input)
+    let mappings = {
+        structs::IndexMappings::try_from(&input)
             .map_err(
                 // Because of thiserror, our error message is pretty nice actually.
                 |err| {
@@ -111,6 +310,18 @@ pub fn enum_index(input: TokenStream) -> TokenStream {
             .unwrap()
     };
 
+    // Find any `#[enum_index(...)]` options; these are not required.
+    let options =
+        { structs::EnumIndexOptions::try_from(&input).unwrap_or_else(|err| panic!("{}", err)) };
+
+    // The mapping that drives `index()`, `From`, `TryFrom` and serde.
+    let primary = {
+        mappings
+            .primary(&input, options.primary.as_ref())
+            .unwrap_or_else(|err| panic!("{}", err))
+    };
+    let params = &primary.params;
+
     let enum_data = {
         func::get_enum_data(&input).unwrap_or_else(
             // Because of thiserror, our error message is pretty nice actually.
@@ -123,74 +334,265 @@ pub fn enum_index(input: TokenStream) -> TokenStream {
     // =================================================================================
     // Resolving #[index(index)]:
 
-    let (match_to_index, match_from_index, match_from_name) = {
-        enum_data.variants.iter().map(|variant| {
-            (
-                variant,
-                IndexAttribute::try_from(variant).unwrap_or_else(|err| panic!("{}", err)),
+    // With #[index_versions(...)], the unnamed mapping follows the latest version.
+    let versions = { structs::IndexVersions::find(&input).unwrap_or_else(|err| panic!("{}", err)) };
+    if versions.is_some() && primary.name.is_some() {
+        panic!(
+            "{}",
+            enum_index_types::errors::EnumIndexError::VersionsWithNamedMapping(
+                input.ident.to_string()
             )
-        })
+        );
+    }
+    if versions.is_some() && options.auto.is_some() {
+        panic!(
+            "{}",
+            enum_index_types::errors::EnumIndexError::VersionsWithAutoIndex(
+                input.ident.to_string()
+            )
+        );
     }
-    .map(
-        // Now that we know we have a index for each variant, lets roll them together
-        // in to a single TokenStream.
-        |(variant, index_attribute)| {
+    let resolve_version = |version: &syn::Ident| {
+        versions
+            .as_ref()
+            .unwrap()
+            .resolve(version, enum_data.variants.iter())
+            .unwrap_or_else(|err| panic!("{}", err))
+    };
+
+    // Any missing #[index(index)] is filled in if #[enum_index(auto = "...")] is given.
+    let resolve = |mapping: &structs::IndexMapping| match &versions {
+        Some(versions) if mapping.name.is_none() => resolve_version(versions.latest()),
+        _ => structs::AutoIndex::resolve(
+            options.auto_index().as_ref(),
+            mapping.name.as_ref(),
+            enum_data.variants.iter(),
+        )
+        .unwrap_or_else(|err| panic!("{}", err)),
+    };
+    let indexed_variants = resolve(primary);
+
+    // `{Enum}Version`, along with `index_for()` and `from_index_for()`.
+    let (version_enum, versioned_index_methods) = match &versions {
+        Some(versions) => (
+            impls::impl_version_enum(&input, versions),
+            impls::impl_versioned_index_methods(
+                &input,
+                params,
+                versions,
+                &versions.iter().map(resolve_version).collect::<Vec<_>>(),
+            ),
+        ),
+        None => (quote!(), quote!()),
+    };
+
+    // `index()`, `try_index()` and `from_index()` of the primary mapping, followed by
+    // `wire_index()` etc. for every named mapping.
+    let index_methods = {
+        std::iter::once(impls::impl_index_methods(
+            params,
+            &options,
+            &indexed_variants,
+            &impls::IndexMethodNames::for_mapping(None),
+        ))
+        .chain(
+            mappings
+                .iter()
+                .filter(|mapping| mapping.name.is_some())
+                .map(|mapping| {
+                    impls::impl_index_methods(
+                        &mapping.params,
+                        &options,
+                        &resolve(mapping),
+                        &impls::IndexMethodNames::for_mapping(mapping.name.as_ref()),
+                    )
+                }),
+        )
+        .collect::<proc_macro2::TokenStream>()
+    };
+
+    let (match_from_name, match_to_name) = indexed_variants
+        .iter()
+        .map(|(variant, _)| {
             let variant_name = &variant.ident;
-            let index = index_attribute.expr();
+            let variant_pattern = func::variant_pattern(variant);
+            let variant_constructor = func::variant_constructor(variant);
+            let ident_str = format_ident!("{}", variant_name).to_string();
+            let ident_ref = &ident_str;
 
             (
-                // match_to_index
-                if params.is_type("String") && func::is_lit_str(index) {
-                    quote!(
-                        Self::#variant_name=>#index.to_string(),
-                    )
-                } else {
-                    quote!(
-                        Self::#variant_name=>#index,
-                    )
-                },
-                // match_from_index
+                // match_from_name
                 quote!(
-                    #index=>Some(Self::#variant_name),
+                    #ident_ref=>Some(#variant_constructor),
+                ),
+                // match_to_name
+                quote!(
+                    #variant_pattern=>#ident_ref,
                 ),
-                // match_from_name
-                {
-                    let ident_str = format_ident!("{}", variant_name).to_string();
-                    let ident_ref = &ident_str;
-                    quote!(
-                        #ident_ref=>Some(Self::#variant_name),
-                    )
-                },
             )
-        },
-    )
-    .fold((quote!(), quote!(), quote!()), |mut lhs, rhs| {
-        lhs.0.extend(rhs.0);
-        lhs.1.extend(rhs.1);
-        lhs.2.extend(rhs.2);
-        lhs
-    });
+        })
+        .fold((quote!(), quote!()), |mut lhs, rhs| {
+            lhs.0.extend(rhs.0);
+            lhs.1.extend(rhs.1);
+            lhs
+        });
+
+    // =================================================================================
+    // Building the prefix trie for `str` indices:
+
+    // This is only possible if every index is a `str` literal known at expansion time.
+    let impl_match_prefix = {
+        // Retired indices are inserted last, so that the current indices take precedence.
+        let lit_strs = indexed_variants
+            .iter()
+            .filter_map(|(variant, variant_index)| {
+                variant_index.expr().map(|index| (variant, index))
+            })
+            .chain(
+                indexed_variants
+                    .iter()
+                    .flat_map(|(variant, variant_index)| {
+                        variant_index
+                            .deprecated()
+                            .iter()
+                            .map(move |index| (variant, index))
+                    }),
+            )
+            .map(|(variant, index)| func::get_lit_str(index).map(|lit| (variant, lit.value())))
+            .collect::<Option<Vec<_>>>();
+
+        match lit_strs {
+            Some(lit_strs) if params.is_str_like() => {
+                let trie = lit_strs.iter().fold(
+                    structs::PrefixTrie::default(),
+                    |mut trie, (variant, index)| {
+                        trie.insert(index, &func::variant_constructor(variant));
+                        trie
+                    },
+                );
+                let body = trie.expand();
+
+                quote! {
+                    /// Find the variant with the longest index that is a prefix of `input`.
+                    ///
+                    /// Returns the variant along with the length of its index in bytes,
+                    /// or [`None`] if no index is a prefix of `input`.
+                    pub fn match_prefix(input: &str) -> Option<(Self, usize)> {
+                        #body
+                    }
+                }
+            }
+            _ => quote!(),
+        }
+    };
 
     let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // `serde::Deserialize` needs an additional `'de` lifetime on top of our own generics.
+    let de_generics = func::generics_with_lifetime(&input.generics, "'de");
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    // With #[enum_index(skipped = "option")], `index()` returns `Option<T>` instead, and
+    // the conversions into `T` become fallible.
+    let impl_into_index = if options.is_index_optional() {
+        quote! {
+            impl #impl_generics TryFrom<#name #ty_generics> for #return_type #where_clause {
+                type Error = EnumIndexError;
+                fn try_from(index: #name #ty_generics) -> Result<Self, Self::Error> {
+                    index.try_index().ok_or_else(
+                        || EnumIndexError::VariantSkipped(index.variant_name().to_string())
+                    )
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics From<#name #ty_generics> for #return_type #where_clause {
+                fn from(index: #name #ty_generics) -> Self {
+                    index.index()
+                }
+            }
+        }
+    };
+
+    // Borrowed string indices can still be converted into an owned `String`.
+    let impl_into_string = match (
+        params.is_static_str() || params.is_cow(),
+        options.is_index_optional(),
+    ) {
+        (false, _) => quote!(),
+        (true, false) => quote! {
+            impl #impl_generics From<#name #ty_generics> for String #where_clause {
+                fn from(index: #name #ty_generics) -> Self {
+                    index.index().into()
+                }
+            }
+        },
+        (true, true) => quote! {
+            impl #impl_generics TryFrom<#name #ty_generics> for String #where_clause {
+                type Error = EnumIndexError;
+                fn try_from(index: #name #ty_generics) -> Result<Self, Self::Error> {
+                    <#return_type>::try_from(index).map(Into::into)
+                }
+            }
+        },
+    };
+    let input_type = params.input_type();
+
+    let descriptions = impls::impl_descriptions(params, &indexed_variants);
+    let meta_accessors = impls::impl_meta_accessors(
+        &input,
+        &mappings,
+        &enum_data.variants.iter().collect::<Vec<_>>(),
+    );
+
+    let impl_display = impls::impl_display(&input, &options);
+    let impl_from_str = impls::impl_from_str(&input, params, &options);
+    let impl_value_enum = impls::impl_value_enum(&input, &options, &indexed_variants);
+    let impl_sqlx = impls::impl_sqlx(&input, params, &options);
+    let impl_rusqlite = impls::impl_rusqlite(&input, params, &options);
+    let impl_diesel = impls::impl_diesel(&input, params, &options, &indexed_variants);
+    let impl_schemars = impls::impl_schemars(&input, params, &options, &indexed_variants);
+    let impl_utoipa = impls::impl_utoipa(&input, params, &options, &indexed_variants);
+    let impl_prost_conversions = impls::impl_prost_conversions(&input, &options, &indexed_variants);
+    let proto_definition =
+        impls::impl_proto_definition(&input, params, &options, &indexed_variants);
+    let impl_arbitrary = impls::impl_arbitrary(&input, &options, &indexed_variants);
+    let impl_proptest = impls::impl_proptest(&input, &options, &indexed_variants);
+    let any_index_strategy =
+        impls::impl_any_index_strategy(&input, params, &options, &indexed_variants);
+    let impl_rkyv = impls::impl_rkyv(&input, params, &options);
+    let impl_checked_bit_pattern =
+        impls::impl_checked_bit_pattern(&input, params, &options, &indexed_variants);
+    let impl_pyo3 = impls::impl_pyo3(&input, params, &options);
+    let impl_wasm = impls::impl_wasm(&input, params, &options);
+    let typescript_definition =
+        impls::impl_typescript_definition(&input, params, &options, &indexed_variants);
 
     // =================================================================================
     // Build the output
     let expanded = quote! {
-        impl #name {
-            fn index(&self) -> #return_type {
+        #version_enum
+        impl #impl_generics #name #ty_generics #where_clause {
+            #index_methods
+            #versioned_index_methods
+            #meta_accessors
+            #descriptions
+            #proto_definition
+            #typescript_definition
+            #any_index_strategy
+            #impl_match_prefix
+
+            /// Get the name of this variant as declared in the enum.
+            pub fn variant_name(&self) -> &'static str {
                 match self {
-                    #match_to_index
-                }
-            }
-            fn from_index(index: #return_type_ref) -> Option<Self> {
-                match index {
-                    #match_from_index
-                    _ => None
+                    #match_to_name
                 }
             }
         }
-        impl EnumIndex for #name {}
-        impl VariantByName for #name {
+        impl #impl_generics EnumIndex for #name #ty_generics #where_clause {}
+        impl #impl_generics VariantByName for #name #ty_generics #where_clause {
             fn by_name(name: &str) -> Option<Self> {
                 match name {
                     #match_from_name
@@ -198,12 +600,9 @@ pub fn enum_index(input: TokenStream) -> TokenStream {
                 }
             }
         }
-        impl From<#name> for #return_type {
-            fn from(index: #name) -> Self {
-                index.index()
-            }
-        }
-        impl TryFrom<#return_type_ref> for #name {
+        #impl_into_index
+        #impl_into_string
+        impl #impl_generics TryFrom<#return_type_ref> for #name #ty_generics #where_clause {
             type Error = EnumIndexError;
             fn try_from(index: #return_type_ref) -> Result<Self, Self::Error> {
                 Self::from_index(&index)
@@ -212,20 +611,40 @@ pub fn enum_index(input: TokenStream) -> TokenStream {
                 )
             }
         }
-        impl serde::Serialize for #name {
+        #impl_display
+        #impl_from_str
+        #impl_value_enum
+        #impl_sqlx
+        #impl_rusqlite
+        #impl_diesel
+        #impl_schemars
+        #impl_utoipa
+        #impl_prost_conversions
+        #impl_arbitrary
+        #impl_proptest
+        #impl_rkyv
+        #impl_checked_bit_pattern
+        #impl_pyo3
+        #impl_wasm
+        impl #impl_generics serde::Serialize for #name #ty_generics #where_clause {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
                 S: serde::Serializer,
             {
-                self.index().serialize(serializer)
+                match self.try_index() {
+                    Some(index) => index.serialize(serializer),
+                    None => Err(serde::ser::Error::custom(
+                        EnumIndexError::VariantSkipped(self.variant_name().to_string())
+                    )),
+                }
             }
         }
-        impl<'de> serde::Deserialize<'de> for #name {
+        impl #de_impl_generics serde::Deserialize<'de> for #name #ty_generics #where_clause {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
-                #return_type::deserialize(deserializer)
+                <#input_type as serde::Deserialize>::deserialize(deserializer)
                 .and_then(
                     | index | Self::try_from(&index as #return_type_ref)
                               .map_err(
@@ -0,0 +1,136 @@
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+/// Struct to parse the optional attribute of #[index_meta_type(key = T, ...)].
+///
+/// This declares the type of each metadata key once for the whole enum, so that every
+/// #[index_meta(key = value)] on the variants is type checked against it.
+#[derive(Debug, Clone, Default)]
+pub struct MetaSchema(Vec<(syn::Ident, syn::Type)>);
+impl syn::parse::Parse for MetaSchema {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let keys = content.parse_terminated::<_, syn::Token![,]>(|item| {
+            let key: syn::Ident = item.parse()?;
+            item.parse::<syn::Token![=]>()?;
+            let t: syn::Type = item.parse()?;
+            Ok((key, t))
+        })?;
+
+        reject_duplicates(keys.iter().map(|(key, _)| key), "#[index_meta_type(...)]")?;
+        Ok(Self(keys.into_iter().collect()))
+    }
+}
+impl MetaSchema {
+    /// Static method to look for the first attribute that matches its own requirement.
+    fn find_attribute(input: &DeriveInput) -> Option<&syn::Attribute> {
+        input
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("index_meta_type"))
+    }
+
+    /// Iterate over the declared keys and their types.
+    pub fn iter(&self) -> impl Iterator<Item = &(syn::Ident, syn::Type)> {
+        self.0.iter()
+    }
+
+    /// Check if a key was declared.
+    pub fn contains(&self, key: &syn::Ident) -> bool {
+        self.0.iter().any(|(declared, _)| declared == key)
+    }
+}
+impl TryFrom<&DeriveInput> for MetaSchema {
+    type Error = errors::EnumIndexError;
+
+    fn try_from(value: &DeriveInput) -> Result<Self, Self::Error> {
+        Self::find_attribute(value)
+            .map(|attr| {
+                syn::parse2(attr.tokens.clone()).map_err(|err| {
+                    errors::EnumIndexError::SynParseError(value.ident.to_string(), err)
+                })
+            })
+            .unwrap_or_else(|| Ok(Self::default()))
+    }
+}
+
+/// Struct to parse the #[index_meta(key = value, ...)] attribute of a variant.
+#[derive(Debug, Clone, Default)]
+pub struct MetaValues(Vec<(syn::Ident, syn::Expr)>);
+impl syn::parse::Parse for MetaValues {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let values = content.parse_terminated::<_, syn::Token![,]>(|item| {
+            let key: syn::Ident = item.parse()?;
+            item.parse::<syn::Token![=]>()?;
+            let value: syn::Expr = item.parse()?;
+            Ok((key, value))
+        })?;
+
+        reject_duplicates(values.iter().map(|(key, _)| key), "#[index_meta(...)]")?;
+        Ok(Self(values.into_iter().collect()))
+    }
+}
+impl MetaValues {
+    /// Find and parse the #[index_meta(...)] attribute of a variant, checking every key
+    /// against the schema.
+    ///
+    /// Variants without the attribute have no values.
+    pub fn find(
+        variant: &syn::Variant,
+        schema: &MetaSchema,
+    ) -> Result<Self, errors::EnumIndexError> {
+        let values: Self = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("index_meta"))
+            .map(|attr| {
+                syn::parse2(attr.tokens.clone()).map_err(|err| {
+                    errors::EnumIndexError::SynParseError(variant.ident.to_string(), err)
+                })
+            })
+            .unwrap_or_else(|| Ok(Self::default()))?;
+
+        match values.0.iter().find(|(key, _)| !schema.contains(key)) {
+            Some((key, _)) => Err(errors::EnumIndexError::MetaKeyNotDeclared(
+                variant.ident.to_string(),
+                key.to_string(),
+            )),
+            None => Ok(values),
+        }
+    }
+
+    /// Get the value of a key, if given.
+    pub fn get(&self, key: &syn::Ident) -> Option<&syn::Expr> {
+        self.0
+            .iter()
+            .find(|(given, _)| given == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Fail on the second occurrence of any key, rather than silently keeping the first.
+fn reject_duplicates<'a>(
+    keys: impl Iterator<Item = &'a syn::Ident>,
+    attribute: &str,
+) -> syn::Result<()> {
+    let mut seen: Vec<&syn::Ident> = Vec::new();
+    for key in keys {
+        if seen.contains(&key) {
+            return Err(syn::parse::Error::new(
+                key.span(),
+                format!(
+                    "metadata key `{}` is given more than once in {}.",
+                    key, attribute
+                ),
+            ));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
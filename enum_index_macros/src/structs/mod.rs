@@ -1,5 +1,10 @@
-mod params;
-pub use params::EnumIndexParams;
+pub use enum_index_syntax::{
+    AutoIndex, DisplayMode, EnumIndexOptions, EnumIndexParams, FromStrMode, IndexMapping,
+    IndexMappings, IndexVersions, RenameRule, VariantIndex,
+};
 
-mod index;
-pub use index::IndexAttribute;
+mod trie;
+pub use trie::PrefixTrie;
+
+mod meta;
+pub use meta::{MetaSchema, MetaValues};
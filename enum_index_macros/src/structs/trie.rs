@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A byte-wise trie of `str` literal indices, built during macro expansion.
+///
+/// This is expanded into nested `match` blocks on the bytes of the input, so that
+/// `match_prefix()` never needs to iterate over all the variants at runtime.
+#[derive(Clone, Debug, Default)]
+pub struct PrefixTrie {
+    variant: Option<TokenStream>,
+    children: BTreeMap<u8, PrefixTrie>,
+}
+impl PrefixTrie {
+    /// Insert an index into the trie, along with the expression constructing its variant.
+    ///
+    /// If the same index is inserted twice, the first variant is kept, mirroring the
+    /// behaviour of `from_index`.
+    pub fn insert(&mut self, index: &str, variant: &TokenStream) {
+        let node = index
+            .bytes()
+            .fold(self, |node, byte| node.children.entry(byte).or_default());
+
+        if node.variant.is_none() {
+            node.variant = Some(variant.clone());
+        }
+    }
+
+    /// Expand this node into a block that updates `found` with the longest match.
+    fn expand_node(&self, depth: usize) -> TokenStream {
+        let record = self.variant.as_ref().map(|variant| {
+            quote!(
+                found = Some((#variant, #depth));
+            )
+        });
+
+        let arms = self.children.iter().map(|(byte, child)| {
+            let child_block = child.expand_node(depth + 1);
+            quote!(
+                Some(#byte) => { #child_block }
+            )
+        });
+
+        let lookup = if self.children.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                match bytes.get(#depth).copied() {
+                    #(#arms)*
+                    _ => {}
+                }
+            )
+        };
+
+        quote!(
+            #record
+            #lookup
+        )
+    }
+
+    /// Expand the whole trie into the body of `match_prefix(input: &str)`.
+    pub fn expand(&self) -> TokenStream {
+        let root = self.expand_node(0);
+
+        quote!(
+            let bytes = input.as_bytes();
+            #[allow(unused_mut)]
+            let mut found: Option<(Self, usize)> = None;
+            #root
+            found
+        )
+    }
+}
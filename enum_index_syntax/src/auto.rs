@@ -0,0 +1,140 @@
+use enum_index_types::errors;
+
+use crate::{func, IndexAttribute, VariantIndex};
+
+/// Where a missing #[index(value)] should be derived from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoIndexMode {
+    /// The explicit `= N` discriminant of the variant, or one more than the previous.
+    Discriminant,
+    /// The ordinal position of the variant, as `start + position * step`.
+    Position,
+    /// The name of the variant as a `str` literal.
+    Name,
+}
+impl AutoIndexMode {
+    pub fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "discriminant" => Ok(Self::Discriminant),
+            "position" => Ok(Self::Position),
+            "name" => Ok(Self::Name),
+            _ => Err(syn::parse::Error::new(
+                lit.span(),
+                "expected one of \"discriminant\", \"position\" or \"name\".",
+            )),
+        }
+    }
+}
+
+/// The settings of #[enum_index(auto = "...", start = N, step = N)].
+#[derive(Debug, Clone, Copy)]
+pub struct AutoIndex {
+    pub mode: AutoIndexMode,
+    pub start: i128,
+    pub step: i128,
+}
+impl AutoIndex {
+    /// Resolve the #[index(value)] of every variant for a mapping.
+    ///
+    /// If `auto` is given, variants without a value for this mapping are given an
+    /// implicit one; otherwise they result in [`errors::EnumIndexError::IndexNotGiven`].
+    pub fn resolve<'a>(
+        auto: Option<&Self>,
+        mapping: Option<&syn::Ident>,
+        variants: impl Iterator<Item = &'a syn::Variant>,
+    ) -> Result<Vec<(&'a syn::Variant, VariantIndex)>, errors::EnumIndexError> {
+        // The last known discriminant, following the same rules as the compiler.
+        let mut discriminant: Option<i128> = None;
+
+        variants
+            .enumerate()
+            .map(|(position, variant)| {
+                let explicit = variant.discriminant.as_ref().map(|(_, expr)| expr);
+                discriminant = match explicit {
+                    Some(expr) => func::eval_int(expr),
+                    None => match discriminant {
+                        Some(previous) => previous.checked_add(1),
+                        None if position == 0 => Some(0),
+                        None => None,
+                    },
+                };
+
+                let attribute = IndexAttribute::find(variant)?.unwrap_or_default();
+                let deprecated = IndexAttribute::find_named(variant, "index_deprecated")?
+                    .unwrap_or_default()
+                    .values_for(mapping)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let variant_index = if attribute.is_skipped_for(mapping) {
+                    VariantIndex::skipped()
+                } else if let Some(expr) = attribute.expr_for(mapping) {
+                    if attribute.has_flag("deprecated") {
+                        // #[index(7, deprecated)]: decoded, but never emitted.
+                        VariantIndex::skipped().with_deprecated([expr.clone()])
+                    } else {
+                        VariantIndex::from_expr(expr.clone())
+                    }
+                } else if let Some(auto) = auto {
+                    auto.implicit_index(variant, position, explicit, discriminant)?
+                } else {
+                    return Err(match mapping {
+                        None => errors::EnumIndexError::IndexNotGiven(variant.ident.to_string()),
+                        Some(mapping) => errors::EnumIndexError::MappingIndexNotGiven(
+                            variant.ident.to_string(),
+                            mapping.to_string(),
+                        ),
+                    });
+                };
+
+                Ok((variant, variant_index.with_deprecated(deprecated)))
+            })
+            .collect()
+    }
+
+    /// Build the implicit index of a single variant.
+    fn implicit_index(
+        &self,
+        variant: &syn::Variant,
+        position: usize,
+        explicit: Option<&syn::Expr>,
+        discriminant: Option<i128>,
+    ) -> Result<VariantIndex, errors::EnumIndexError> {
+        let not_resolved = |reason: &str| {
+            errors::EnumIndexError::AutoIndexNotResolved(
+                variant.ident.to_string(),
+                reason.to_string(),
+            )
+        };
+
+        let expr = match self.mode {
+            AutoIndexMode::Discriminant => match (explicit, discriminant) {
+                (_, Some(value)) => func::int_expr(value),
+                // A non-literal discriminant, such as a `const`, can be used as is.
+                (Some(expr), None) => return Ok(VariantIndex::from_expr(expr.clone())),
+                (None, None) => {
+                    return Err(not_resolved(
+                        "the previous discriminant is not an integer literal",
+                    ))
+                }
+            },
+            AutoIndexMode::Position => {
+                let value = i128::try_from(position)
+                    .ok()
+                    .and_then(|position| position.checked_mul(self.step))
+                    .and_then(|offset| offset.checked_add(self.start))
+                    .ok_or_else(|| not_resolved("the position overflowed"))?;
+                func::int_expr(value)
+            }
+            AutoIndexMode::Name => syn::Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: syn::Lit::Str(syn::LitStr::new(
+                    &variant.ident.to_string(),
+                    variant.ident.span(),
+                )),
+            }),
+        };
+
+        Ok(VariantIndex::generated(expr))
+    }
+}
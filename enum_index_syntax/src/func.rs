@@ -0,0 +1,66 @@
+/// Evaluate an integer literal expression, such as `3` or `-0x10`.
+pub fn eval_int(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Group(g) => eval_int(&g.expr),
+        syn::Expr::Paren(p) => eval_int(&p.expr),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => eval_int(expr).and_then(i128::checked_neg),
+        _ => None,
+    }
+}
+
+/// Build an unsuffixed integer literal expression, so that it fits any integer type.
+pub fn int_expr(value: i128) -> syn::Expr {
+    syn::parse_str(&value.to_string()).unwrap()
+}
+
+/// Check if Type matches.
+pub fn is_type(t: &syn::Type, value: &str) -> bool {
+    if let syn::Type::Path(t_path) = t {
+        t_path
+            .path
+            .segments
+            .iter()
+            .map(|seg| seg.ident.to_string())
+            .reduce(|lhs, rhs| lhs + "::" + &rhs)
+            .map(|s| s.as_str() == value)
+            .unwrap_or(false)
+    } else {
+        false
+    }
+}
+
+/// Check if the last segment of a Type path matches, ignoring any generic arguments.
+///
+/// This is for types such as `Cow<'static, str>` which may be written with or without
+/// their full path.
+pub fn is_type_ident(t: &syn::Type, value: &str) -> bool {
+    if let syn::Type::Path(t_path) = ungroup_type(t) {
+        t_path
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident == value)
+            .unwrap_or(false)
+    } else {
+        false
+    }
+}
+
+/// Strip any invisible groups around a Type.
+///
+/// These are introduced when a type is passed through a `macro_rules!` `$t:ty` fragment.
+pub fn ungroup_type(t: &syn::Type) -> &syn::Type {
+    match t {
+        syn::Type::Group(g) => ungroup_type(&g.elem),
+        syn::Type::Paren(p) => ungroup_type(&p.elem),
+        _ => t,
+    }
+}
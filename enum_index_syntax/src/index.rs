@@ -0,0 +1,183 @@
+use enum_index_types::errors;
+
+/// An #[index(value)] attribute.
+///
+/// The attribute may contain positional values such as `#[index(3)]` and `#[index(skip)]`,
+/// as well as named values such as `#[index(wire = 3, db = "cancelled")]` for enums with
+/// multiple mappings. These are resolved into a [`VariantIndex`] per mapping.
+#[derive(Clone, Debug, Default)]
+pub struct IndexAttribute {
+    positional: Vec<syn::Expr>,
+    named: Vec<(syn::Ident, syn::Expr)>,
+}
+impl IndexAttribute {
+    /// Find and parse the #[index(value)] attribute of a variant, if there is one.
+    pub fn find(variant: &syn::Variant) -> Result<Option<Self>, errors::EnumIndexError> {
+        Self::find_named(variant, "index")
+    }
+
+    /// Find and parse an attribute of the same syntax, such as #[index_deprecated(value)].
+    pub fn find_named(
+        variant: &syn::Variant,
+        name: &str,
+    ) -> Result<Option<Self>, errors::EnumIndexError> {
+        variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident(name))
+            .map(|attr| {
+                syn::parse2(attr.tokens.clone()).map_err(|err| {
+                    errors::EnumIndexError::SynParseError(variant.ident.to_string(), err)
+                })
+            })
+            .transpose()
+    }
+
+    /// Check if a positional flag such as `skip` was given.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.positional.iter().any(|expr| is_flag(expr, flag))
+    }
+
+    /// Get the value for a mapping; [`None`] refers to the unnamed mapping of
+    /// #[index_type(T)], which takes the first positional value that is not a flag.
+    pub fn expr_for(&self, mapping: Option<&syn::Ident>) -> Option<&syn::Expr> {
+        match mapping {
+            None => self
+                .positional
+                .iter()
+                .find(|expr| !FLAGS.iter().any(|flag| is_flag(expr, flag))),
+            Some(mapping) => self
+                .named
+                .iter()
+                .find(|(key, _)| key == mapping)
+                .map(|(_, expr)| expr),
+        }
+    }
+
+    /// Get all the values for a mapping, such as #[index_deprecated(7, 8)].
+    pub fn values_for<'a>(
+        &'a self,
+        mapping: Option<&'a syn::Ident>,
+    ) -> impl Iterator<Item = &'a syn::Expr> {
+        let positional = self
+            .positional
+            .iter()
+            .filter(move |expr| mapping.is_none() && !FLAGS.iter().any(|flag| is_flag(expr, flag)));
+        let named = self
+            .named
+            .iter()
+            .filter(move |(key, _)| Some(key) == mapping)
+            .map(|(_, expr)| expr);
+
+        positional.chain(named)
+    }
+
+    /// Check if the variant is skipped for a mapping, either entirely with
+    /// #[index(skip)] or for a single mapping with #[index(name = skip)].
+    pub fn is_skipped_for(&self, mapping: Option<&syn::Ident>) -> bool {
+        self.has_flag("skip")
+            || (mapping.is_some() && self.expr_for(mapping).is_some_and(|e| is_flag(e, "skip")))
+    }
+}
+impl syn::parse::Parse for IndexAttribute {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let values = {
+            content
+                .parse_terminated::<syn::Expr, syn::Token![,]>(syn::Expr::parse)
+                .map_err(|err| {
+                    syn::parse::Error::new(
+                        err.span(),
+                        "value provided in #[index(T)] is not a valid expression.",
+                    )
+                })
+        }?;
+
+        // `name = value` is parsed as an assignment expression; split those out.
+        Ok(values
+            .into_iter()
+            .fold(Self::default(), |mut attribute, value| {
+                match value {
+                    syn::Expr::Assign(syn::ExprAssign { left, right, .. })
+                        if matches!(&*left, syn::Expr::Path(p) if p.path.get_ident().is_some()) =>
+                    {
+                        if let syn::Expr::Path(p) = *left {
+                            attribute
+                                .named
+                                .push((p.path.get_ident().unwrap().clone(), *right));
+                        }
+                    }
+                    value => attribute.positional.push(value),
+                }
+                attribute
+            }))
+    }
+}
+
+/// Positional identifiers in #[index(...)] that are flags rather than values.
+const FLAGS: &[&str] = &["skip", "deprecated"];
+
+/// Check if an expression is the bare identifier `flag`.
+fn is_flag(expr: &syn::Expr, flag: &str) -> bool {
+    matches!(expr, syn::Expr::Path(p) if p.path.is_ident(flag))
+}
+
+/// The resolved index of a variant for a single mapping.
+#[derive(Clone, Debug)]
+pub struct VariantIndex {
+    expr: Option<syn::Expr>,
+    deprecated: Vec<syn::Expr>,
+    is_generated: bool,
+}
+impl VariantIndex {
+    /// Build an index from an expression, as if it was declared as #[index(expr)].
+    pub fn from_expr(expr: syn::Expr) -> Self {
+        Self {
+            expr: Some(expr),
+            deprecated: Vec::new(),
+            is_generated: false,
+        }
+    }
+
+    /// Build an implicit index from an expression that is not in the source, such as
+    /// the position of the variant with #[enum_index(auto = "position")].
+    pub fn generated(expr: syn::Expr) -> Self {
+        Self {
+            is_generated: true,
+            ..Self::from_expr(expr)
+        }
+    }
+
+    /// Build an index for a variant declared with #[index(skip)].
+    pub fn skipped() -> Self {
+        Self {
+            expr: None,
+            deprecated: Vec::new(),
+            is_generated: false,
+        }
+    }
+
+    /// Add retired indices, which still map to this variant but are never emitted.
+    pub fn with_deprecated(mut self, deprecated: impl IntoIterator<Item = syn::Expr>) -> Self {
+        self.deprecated.extend(deprecated);
+        self
+    }
+
+    /// The index expression, or [`None`] if the variant is skipped.
+    pub fn expr(&self) -> Option<&syn::Expr> {
+        self.expr.as_ref()
+    }
+
+    /// The retired indices of this variant.
+    pub fn deprecated(&self) -> &[syn::Expr] {
+        &self.deprecated
+    }
+
+    /// Check if the index expression was generated rather than written in the source, in
+    /// which case it has no location in the source.
+    pub fn is_generated(&self) -> bool {
+        self.is_generated
+    }
+}
@@ -0,0 +1,30 @@
+//! This crate parses the attributes of `#[derive(EnumIndex)]`, and resolves the index of
+//! each variant from them.
+//!
+//! It is shared by [`enum_index_macros`], which generates code from the resolved indices,
+//! and the `enum_index` binary, which lists them; so that both always agree on the
+//! index of every variant.
+//!
+//! [`enum_index_macros`]: ../enum_index_macros
+//!
+
+mod params;
+pub use params::EnumIndexParams;
+
+mod mappings;
+pub use mappings::{IndexMapping, IndexMappings};
+
+mod options;
+pub use options::{DisplayMode, EnumIndexOptions, FromStrMode, RenameRule, SkippedMode};
+
+mod index;
+pub use index::{IndexAttribute, VariantIndex};
+
+mod auto;
+pub use auto::{AutoIndex, AutoIndexMode};
+
+mod versions;
+pub use versions::IndexVersions;
+
+mod func;
+pub use func::{eval_int, int_expr};
@@ -0,0 +1,101 @@
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::EnumIndexParams;
+
+/// A single mapping declared in #[index_type(...)].
+#[derive(Debug, Clone)]
+pub struct IndexMapping {
+    /// The name of the mapping, or [`None`] for the unnamed #[index_type(T)].
+    pub name: Option<syn::Ident>,
+    pub params: EnumIndexParams,
+}
+
+/// Struct to parse the follow up attribute of #[index_type(T)].
+///
+/// This is either a single unnamed type, as in #[index_type(u16)], or a list of named
+/// mappings, as in #[index_type(wire = u16, db = String)]. Each named mapping is given
+/// its own `wire_index()`, `from_wire_index()` etc.
+#[derive(Debug, Clone)]
+pub struct IndexMappings(Vec<IndexMapping>);
+impl syn::parse::Parse for IndexMappings {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let is_named = content.peek(syn::Ident) && content.peek2(syn::Token![=]);
+
+        if is_named {
+            content
+                .parse_terminated::<_, syn::Token![,]>(|item| {
+                    let name: syn::Ident = item.parse()?;
+                    item.parse::<syn::Token![=]>()?;
+                    let params: EnumIndexParams = item.parse()?;
+
+                    Ok(IndexMapping {
+                        name: Some(name),
+                        params,
+                    })
+                })
+                .map(|mappings| Self(mappings.into_iter().collect()))
+        } else {
+            Ok(Self(vec![IndexMapping {
+                name: None,
+                params: content.parse()?,
+            }]))
+        }
+    }
+}
+impl IndexMappings {
+    /// Static method to look for the first attribute that matches its own requirement.
+    fn find_attribute(input: &DeriveInput) -> Option<&syn::Attribute> {
+        input.attrs.iter().find(|attr| {
+            attr.path.segments.len() == 1 && attr.path.segments[0].ident == "index_type"
+        })
+    }
+
+    /// Iterate over all the mappings.
+    pub fn iter(&self) -> impl Iterator<Item = &IndexMapping> {
+        self.0.iter()
+    }
+
+    /// Get the mapping that drives `index()`, `From`, `TryFrom` and serde.
+    ///
+    /// This is the mapping named by `primary`, or the first mapping if not specified.
+    pub fn primary(
+        &self,
+        input: &DeriveInput,
+        primary: Option<&syn::LitStr>,
+    ) -> Result<&IndexMapping, errors::EnumIndexError> {
+        match primary {
+            None => Ok(&self.0[0]),
+            Some(primary) => self
+                .iter()
+                .find(|mapping| {
+                    mapping
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| *name == primary.value())
+                })
+                .ok_or_else(|| {
+                    errors::EnumIndexError::MappingNotFound(
+                        input.ident.to_string(),
+                        primary.value(),
+                    )
+                }),
+        }
+    }
+}
+impl TryFrom<&DeriveInput> for IndexMappings {
+    type Error = errors::EnumIndexError;
+
+    fn try_from(value: &DeriveInput) -> Result<Self, Self::Error> {
+        let attr = Self::find_attribute(value).ok_or(
+            errors::EnumIndexError::ParamsMetaNotFound(value.ident.to_string()),
+        )?;
+
+        syn::parse2(attr.tokens.clone())
+            .map_err(|err| errors::EnumIndexError::SynParseError(value.ident.to_string(), err))
+    }
+}
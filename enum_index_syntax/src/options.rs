@@ -0,0 +1,374 @@
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::{AutoIndex, AutoIndexMode};
+
+/// Whether a generated impl should use the index or the name of a variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    Index,
+    Name,
+}
+impl DisplayMode {
+    fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "index" => Ok(Self::Index),
+            "name" => Ok(Self::Name),
+            _ => Err(syn::parse::Error::new(
+                lit.span(),
+                "expected one of \"index\" or \"name\".",
+            )),
+        }
+    }
+}
+
+/// What a generated [`FromStr`] impl should attempt to parse.
+///
+/// [`FromStr`]: std::str::FromStr
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromStrMode {
+    Index,
+    Name,
+    Both,
+}
+impl FromStrMode {
+    fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "index" => Ok(Self::Index),
+            "name" => Ok(Self::Name),
+            "both" => Ok(Self::Both),
+            _ => Err(syn::parse::Error::new(
+                lit.span(),
+                "expected one of \"index\", \"name\" or \"both\".",
+            )),
+        }
+    }
+}
+
+/// What `index()` should do for variants declared with #[index(skip)].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkippedMode {
+    /// `index()` returns `T`, and panics for skipped variants.
+    #[default]
+    Panic,
+    /// `index()` returns `Option<T>`, which is [`None`] for skipped variants.
+    Option,
+}
+impl SkippedMode {
+    fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "panic" => Ok(Self::Panic),
+            "option" => Ok(Self::Option),
+            _ => Err(syn::parse::Error::new(
+                lit.span(),
+                "expected one of \"panic\" or \"option\".",
+            )),
+        }
+    }
+}
+
+/// How the name of a variant is converted for generated names, such as the possible
+/// values of `clap::ValueEnum`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenameRule {
+    /// `MyVariant` is kept as `MyVariant`.
+    Verbatim,
+    /// `MyVariant` becomes `myvariant`.
+    Lowercase,
+    /// `MyVariant` becomes `MYVARIANT`.
+    Uppercase,
+    /// `MyVariant` becomes `myVariant`.
+    CamelCase,
+    /// `MyVariant` becomes `MyVariant`.
+    PascalCase,
+    /// `MyVariant` becomes `my_variant`.
+    SnakeCase,
+    /// `MyVariant` becomes `MY_VARIANT`.
+    ScreamingSnakeCase,
+    /// `MyVariant` becomes `my-variant`.
+    #[default]
+    KebabCase,
+}
+impl RenameRule {
+    fn from_lit(lit: &syn::LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "verbatim" => Ok(Self::Verbatim),
+            "lowercase" => Ok(Self::Lowercase),
+            "UPPERCASE" => Ok(Self::Uppercase),
+            "camelCase" => Ok(Self::CamelCase),
+            "PascalCase" => Ok(Self::PascalCase),
+            "snake_case" => Ok(Self::SnakeCase),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "kebab-case" => Ok(Self::KebabCase),
+            _ => Err(syn::parse::Error::new(
+                lit.span(),
+                "expected one of \"verbatim\", \"lowercase\", \"UPPERCASE\", \"camelCase\", \
+                 \"PascalCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\" or \"kebab-case\".",
+            )),
+        }
+    }
+
+    /// Split a variant name into lowercase words, at underscores and at the start of
+    /// each capitalised word.
+    fn words(name: &str) -> Vec<String> {
+        let chars = name.chars().collect::<Vec<_>>();
+
+        chars
+            .iter()
+            .enumerate()
+            .fold(Vec::<String>::new(), |mut words, (pos, c)| {
+                let is_boundary = pos > 0
+                    && c.is_uppercase()
+                    && (chars[pos - 1].is_lowercase()
+                        || chars[pos - 1].is_numeric()
+                        || chars.get(pos + 1).is_some_and(|next| next.is_lowercase())
+                            && chars[pos - 1].is_uppercase());
+
+                match (c, words.last_mut()) {
+                    ('_', _) => words.push(String::new()),
+                    (_, Some(word)) if !is_boundary => word.extend(c.to_lowercase()),
+                    _ => words.push(c.to_lowercase().collect()),
+                }
+                words
+            })
+            .into_iter()
+            .filter(|word| !word.is_empty())
+            .collect()
+    }
+
+    /// Apply the rule to a variant name.
+    pub fn apply(&self, name: &str) -> String {
+        let capitalise = |word: &String| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        };
+        let words = Self::words(name);
+
+        match self {
+            Self::Verbatim => name.to_string(),
+            Self::Lowercase => name.to_lowercase(),
+            Self::Uppercase => name.to_uppercase(),
+            Self::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(pos, word)| {
+                    if pos == 0 {
+                        word.clone()
+                    } else {
+                        capitalise(word)
+                    }
+                })
+                .collect(),
+            Self::PascalCase => words.iter().map(capitalise).collect(),
+            Self::SnakeCase => words.join("_"),
+            Self::ScreamingSnakeCase => words.join("_").to_uppercase(),
+            Self::KebabCase => words.join("-"),
+        }
+    }
+}
+
+/// Struct to parse the optional attribute of #[enum_index(key = value, ...)].
+///
+/// Unlike #[index_type(T)], this attribute is not required; each key switches on
+/// additional generated code. The attribute may be repeated, in which case the keys
+/// are merged together.
+#[derive(Debug, Clone, Default)]
+pub struct EnumIndexOptions {
+    pub display: Option<DisplayMode>,
+    pub from_str: Option<FromStrMode>,
+    pub skipped: Option<SkippedMode>,
+    pub auto: Option<AutoIndexMode>,
+    pub start: Option<i128>,
+    pub step: Option<i128>,
+    pub primary: Option<syn::LitStr>,
+    pub clap: Option<DisplayMode>,
+    pub rename_all: Option<RenameRule>,
+    /// The `sql_type` of `diesel(sql_type = T)`.
+    pub diesel: Option<syn::Type>,
+    /// The path of the `prost` generated enum to convert to and from.
+    pub prost: Option<syn::Path>,
+    pub proto_definition: Option<bool>,
+    pub bytemuck: Option<bool>,
+    pub typescript_definition: Option<bool>,
+    pub sqlx: Option<bool>,
+    pub rusqlite: Option<bool>,
+    pub schemars: Option<bool>,
+    pub utoipa: Option<bool>,
+    pub arbitrary: Option<bool>,
+    pub proptest: Option<bool>,
+    pub rkyv: Option<bool>,
+    pub pyo3: Option<bool>,
+    pub wasm: Option<bool>,
+}
+impl syn::parse::Parse for EnumIndexOptions {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let mut options = Self::default();
+
+        while !content.is_empty() {
+            let key: syn::Ident = content.parse()?;
+
+            match key.to_string().as_str() {
+                "display" => {
+                    options.display = Some(DisplayMode::from_lit(&parse_lit_str(&content)?)?)
+                }
+                "from_str" => {
+                    options.from_str = Some(FromStrMode::from_lit(&parse_lit_str(&content)?)?)
+                }
+                "skipped" => {
+                    options.skipped = Some(SkippedMode::from_lit(&parse_lit_str(&content)?)?)
+                }
+                "auto" => options.auto = Some(AutoIndexMode::from_lit(&parse_lit_str(&content)?)?),
+                "start" => options.start = Some(parse_int(&content)?),
+                "step" => options.step = Some(parse_int(&content)?),
+                "primary" => options.primary = Some(parse_lit_str(&content)?),
+                "clap" => options.clap = Some(DisplayMode::from_lit(&parse_lit_str(&content)?)?),
+                "rename_all" => {
+                    options.rename_all = Some(RenameRule::from_lit(&parse_lit_str(&content)?)?)
+                }
+                "diesel" => options.diesel = Some(parse_diesel(&content)?),
+                "prost" => options.prost = Some(parse_lit_str(&content)?.parse()?),
+                "proto_definition" => options.proto_definition = Some(parse_bool(&content)?),
+                "bytemuck" => options.bytemuck = Some(parse_bool(&content)?),
+                "typescript_definition" => {
+                    options.typescript_definition = Some(parse_bool(&content)?)
+                }
+                "sqlx" => options.sqlx = Some(parse_bool(&content)?),
+                "rusqlite" => options.rusqlite = Some(parse_bool(&content)?),
+                "schemars" => options.schemars = Some(parse_bool(&content)?),
+                "utoipa" => options.utoipa = Some(parse_bool(&content)?),
+                "arbitrary" => options.arbitrary = Some(parse_bool(&content)?),
+                "proptest" => options.proptest = Some(parse_bool(&content)?),
+                "rkyv" => options.rkyv = Some(parse_bool(&content)?),
+                "pyo3" => options.pyo3 = Some(parse_bool(&content)?),
+                "wasm" => options.wasm = Some(parse_bool(&content)?),
+                _ => {
+                    return Err(syn::parse::Error::new(
+                        key.span(),
+                        format!("unknown option `{}` in #[enum_index(...)].", key),
+                    ))
+                }
+            }
+
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        Ok(options)
+    }
+}
+impl EnumIndexOptions {
+    /// Static method to look for all the attributes that match its own requirement.
+    fn find_attributes(input: &DeriveInput) -> impl Iterator<Item = &syn::Attribute> {
+        input.attrs.iter().filter(|attr| {
+            attr.path.segments.len() == 1 && attr.path.segments[0].ident == "enum_index"
+        })
+    }
+
+    /// Merge the keys of another set of options into this one.
+    ///
+    /// Keys that were already set are kept.
+    fn merge(self, other: Self) -> Self {
+        Self {
+            display: self.display.or(other.display),
+            from_str: self.from_str.or(other.from_str),
+            skipped: self.skipped.or(other.skipped),
+            auto: self.auto.or(other.auto),
+            start: self.start.or(other.start),
+            step: self.step.or(other.step),
+            primary: self.primary.or(other.primary),
+            clap: self.clap.or(other.clap),
+            rename_all: self.rename_all.or(other.rename_all),
+            diesel: self.diesel.or(other.diesel),
+            prost: self.prost.or(other.prost),
+            proto_definition: self.proto_definition.or(other.proto_definition),
+            bytemuck: self.bytemuck.or(other.bytemuck),
+            typescript_definition: self.typescript_definition.or(other.typescript_definition),
+            sqlx: self.sqlx.or(other.sqlx),
+            rusqlite: self.rusqlite.or(other.rusqlite),
+            schemars: self.schemars.or(other.schemars),
+            utoipa: self.utoipa.or(other.utoipa),
+            arbitrary: self.arbitrary.or(other.arbitrary),
+            proptest: self.proptest.or(other.proptest),
+            rkyv: self.rkyv.or(other.rkyv),
+            pyo3: self.pyo3.or(other.pyo3),
+            wasm: self.wasm.or(other.wasm),
+        }
+    }
+
+    /// The settings for implicit indices, if `auto` was given.
+    pub fn auto_index(&self) -> Option<AutoIndex> {
+        self.auto.map(|mode| AutoIndex {
+            mode,
+            start: self.start.unwrap_or(0),
+            step: self.step.unwrap_or(1),
+        })
+    }
+
+    /// Whether `index()` should return `Option<T>` instead of panicking.
+    pub fn is_index_optional(&self) -> bool {
+        self.skipped.unwrap_or_default() == SkippedMode::Option
+    }
+}
+impl TryFrom<&DeriveInput> for EnumIndexOptions {
+    type Error = errors::EnumIndexError;
+
+    fn try_from(value: &DeriveInput) -> Result<Self, Self::Error> {
+        Self::find_attributes(value).try_fold(Self::default(), |options, attr| {
+            syn::parse2(attr.tokens.clone())
+                .map(|other| options.merge(other))
+                .map_err(|err| errors::EnumIndexError::SynParseError(value.ident.to_string(), err))
+        })
+    }
+}
+
+/// Parse the `= "value"` following a key.
+fn parse_lit_str(content: syn::parse::ParseStream) -> syn::Result<syn::LitStr> {
+    content.parse::<syn::Token![=]>()?;
+    content.parse()
+}
+
+/// Parse the `= true` or `= false` following a key.
+fn parse_bool(content: syn::parse::ParseStream) -> syn::Result<bool> {
+    content.parse::<syn::Token![=]>()?;
+    Ok(content.parse::<syn::LitBool>()?.value)
+}
+
+/// Parse the `= N` following a key, where `N` is a possibly negative integer literal.
+fn parse_int(content: syn::parse::ParseStream) -> syn::Result<i128> {
+    content.parse::<syn::Token![=]>()?;
+    let is_negative = content.parse::<Option<syn::Token![-]>>()?.is_some();
+    let lit: syn::LitInt = content.parse()?;
+    let value: i128 = lit.base10_parse()?;
+
+    Ok(if is_negative { -value } else { value })
+}
+
+/// Parse the `(sql_type = T)` following `diesel`.
+fn parse_diesel(content: syn::parse::ParseStream) -> syn::Result<syn::Type> {
+    let inner;
+    syn::parenthesized!(inner in content);
+
+    let key: syn::Ident = inner.parse()?;
+    if key != "sql_type" {
+        return Err(syn::parse::Error::new(
+            key.span(),
+            format!(
+                "unknown option `{}` in diesel(...); expected `sql_type`.",
+                key
+            ),
+        ));
+    }
+    inner.parse::<syn::Token![=]>()?;
+    let sql_type = inner.parse()?;
+    inner.parse::<Option<syn::Token![,]>>()?;
+
+    Ok(sql_type)
+}
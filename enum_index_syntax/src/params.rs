@@ -0,0 +1,167 @@
+use quote::quote;
+
+use crate::func;
+
+/// Struct to parse the `T` of the follow up attribute #[index_type(T)].
+///
+/// To use #[derive(EnumIndex)], there must be a follow up attribute of
+/// #[index_type(T)]. This is to indicate the return type of value. See
+/// [`IndexMappings`](crate::IndexMappings) for the attribute itself.
+///
+/// `T` must be a valid type for ALL values provided subsequently.
+///
+/// Besides simple identifiers, `&'static str` and `Cow<'static, str>` are accepted so
+/// that string indices can be returned without allocating.
+///
+/// See: https://stackoverflow.com/questions/56188700/how-do-i-make-my-custom-derive-macro-accept-trait-generic-parameters
+#[derive(Debug, Clone)]
+pub struct EnumIndexParams {
+    /// The type as written in #[index_type(T)].
+    pub specified: syn::Type,
+    pub is_ref: bool,
+    pub lifetime: Option<syn::Lifetime>,
+    pub value_ident: syn::Ident,
+    pub index_type: syn::Type,
+}
+impl syn::parse::Parse for EnumIndexParams {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let specified: syn::Type = input.parse().map_err(|err| {
+            syn::parse::Error::new(
+                err.span(),
+                "value type provided in #[index_type(T)] is not a valid Type.",
+            )
+        })?;
+
+        // Check if the specified type is a &T, and unwrap it if so.
+        let (is_ref, lifetime, index_type) = match func::ungroup_type(&specified) {
+            syn::Type::Reference(type_ref) => {
+                if type_ref.mutability.is_some() {
+                    return Err(syn::parse::Error::new_spanned(
+                        type_ref,
+                        "mutable references are not supported in #[index_type(T)].",
+                    ));
+                }
+                let is_static = matches!(
+                    &type_ref.lifetime,
+                    Some(lifetime) if lifetime.ident == "static"
+                );
+                if func::is_type_ident(&type_ref.elem, "str") && !is_static {
+                    return Err(syn::parse::Error::new_spanned(
+                        type_ref,
+                        "only `&'static str` is supported as a string reference in #[index_type(T)].",
+                    ));
+                }
+                (
+                    true,
+                    type_ref.lifetime.clone(),
+                    func::ungroup_type(&type_ref.elem).clone(),
+                )
+            }
+            t => (false, None, t.clone()),
+        };
+
+        let value_ident: syn::Ident = match &index_type {
+            syn::Type::Path(t_path) if t_path.qself.is_none() => t_path
+                .path
+                .segments
+                .last()
+                .map(|seg| seg.ident.clone())
+                .ok_or_else(|| syn::parse::Error::new_spanned(&index_type, "empty type path."))?,
+            _ => {
+                return Err(syn::parse::Error::new_spanned(
+                    &index_type,
+                    "value type provided in #[index_type(T)] is not a valid Identifier.",
+                ))
+            }
+        };
+
+        let is_complex = !func::is_type_ident(&index_type, "Cow")
+            && matches!(
+                &index_type,
+                syn::Type::Path(t_path) if t_path.path.segments.iter().any(|seg| !seg.arguments.is_empty())
+            );
+        if is_complex {
+            let msg = format!(
+                "Complex type of `{}<>` detected; please use a Type alias for complex value types.",
+                value_ident
+            );
+            return Err(syn::parse::Error::new(value_ident.span(), msg.as_str()));
+        }
+
+        Ok(Self {
+            specified,
+            is_ref,
+            lifetime,
+            value_ident,
+            index_type,
+        })
+    }
+}
+impl EnumIndexParams {
+    /// Check if the type matches
+    pub fn is_type(&self, value: &str) -> bool {
+        func::is_type(&self.index_type, value)
+    }
+
+    /// Check if the type is `&'static str`.
+    pub fn is_static_str(&self) -> bool {
+        self.is_ref
+            && matches!(&self.lifetime, Some(lifetime) if lifetime.ident == "static")
+            && self.is_type("str")
+    }
+
+    /// Check if the type is `Cow<'static, str>`.
+    pub fn is_cow(&self) -> bool {
+        !self.is_ref && func::is_type_ident(&self.index_type, "Cow")
+    }
+
+    /// Check if indices of this type are looked up by `&str`.
+    pub fn is_str_like(&self) -> bool {
+        self.is_type("String") || self.is_static_str() || self.is_cow()
+    }
+
+    /// Return TokenStream of itself as &T.
+    pub fn return_type_ref(&self) -> syn::Type {
+        syn::Type::from(syn::TypeReference {
+            and_token: syn::Token![&](self.value_ident.span()),
+            lifetime: None,
+            mutability: None,
+            elem: Box::new(if self.is_str_like() {
+                syn::parse2(quote!(str)).unwrap()
+            } else {
+                self.index_type.clone()
+            }),
+        })
+    }
+
+    /// Return TokenStream of itself as T.
+    pub fn return_type_owned(&self) -> syn::Type {
+        self.index_type.clone()
+    }
+
+    /// Return TokenStream as &T if originally specified as such, otherwise return T.
+    pub fn return_type_specified(&self) -> syn::Type {
+        match self.is_ref {
+            false => self.return_type_owned(),
+            true => syn::Type::from(syn::TypeReference {
+                and_token: syn::Token![&](self.value_ident.span()),
+                lifetime: self.lifetime.clone(),
+                mutability: None,
+                elem: Box::new(self.index_type.clone()),
+            }),
+        }
+    }
+
+    /// Return the owned type that external input (such as a deserializer or a `str`)
+    /// should be read into before looking up the index.
+    ///
+    /// Borrowed string types cannot outlive their input, so they are read into an owned
+    /// [`String`] first.
+    pub fn input_type(&self) -> syn::Type {
+        if self.is_str_like() {
+            syn::parse2(quote!(String)).unwrap()
+        } else {
+            self.return_type_owned()
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use quote::format_ident;
+use syn::DeriveInput;
+
+use enum_index_types::errors;
+
+use crate::{IndexAttribute, VariantIndex};
+
+/// Struct to parse the optional attribute of #[index_versions(v1, v2, ...)].
+///
+/// The versions are declared in ascending order; a `{Enum}Version` enum is generated
+/// with one variant for each. Variants can then be given a value per version with
+/// #[index(v1 = 3, v2 = 30)], and limited to a range of versions with
+/// #[index(since = v1, until = v2)], both inclusive.
+#[derive(Debug, Clone)]
+pub struct IndexVersions(Vec<syn::Ident>);
+impl syn::parse::Parse for IndexVersions {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::parse::Error> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let versions = content.parse_terminated::<syn::Ident, syn::Token![,]>(syn::Ident::parse)?;
+        if versions.is_empty() {
+            return Err(content.error("at least one version must be declared."));
+        }
+
+        Ok(Self(versions.into_iter().collect()))
+    }
+}
+impl IndexVersions {
+    /// Find and parse the #[index_versions(...)] attribute, if there is one.
+    pub fn find(input: &DeriveInput) -> Result<Option<Self>, errors::EnumIndexError> {
+        input
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("index_versions"))
+            .map(|attr| {
+                syn::parse2(attr.tokens.clone()).map_err(|err| {
+                    errors::EnumIndexError::SynParseError(input.ident.to_string(), err)
+                })
+            })
+            .transpose()
+    }
+
+    /// The name of the generated version enum, such as `StatusVersion`.
+    pub fn type_name(input: &DeriveInput) -> syn::Ident {
+        format_ident!("{}Version", input.ident)
+    }
+
+    /// Iterate over all the versions in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &syn::Ident> {
+        self.0.iter()
+    }
+
+    /// The latest version, which drives `index()` and `from_index()`.
+    pub fn latest(&self) -> &syn::Ident {
+        self.0.last().unwrap()
+    }
+
+    /// Find the position of a version named in `since = ...` or `until = ...`.
+    fn position_of(
+        &self,
+        variant: &syn::Variant,
+        expr: &syn::Expr,
+    ) -> Result<usize, errors::EnumIndexError> {
+        self.0
+            .iter()
+            .position(|version| matches!(expr, syn::Expr::Path(p) if p.path.is_ident(version)))
+            .ok_or_else(|| {
+                errors::EnumIndexError::VersionNotFound(
+                    variant.ident.to_string(),
+                    quote::quote!(#expr).to_string(),
+                )
+            })
+    }
+
+    /// Resolve the index of every variant for a version.
+    ///
+    /// The value for a version is the one stated for the latest version up to and
+    /// including it, falling back to the unversioned #[index(value)]. Variants outside of
+    /// their `since` and `until` range are skipped, along with their retired indices.
+    pub fn resolve<'a>(
+        &self,
+        version: &syn::Ident,
+        variants: impl Iterator<Item = &'a syn::Variant>,
+    ) -> Result<Vec<(&'a syn::Variant, VariantIndex)>, errors::EnumIndexError> {
+        let position = self.0.iter().position(|v| v == version).unwrap();
+
+        variants
+            .map(|variant| {
+                let attribute = IndexAttribute::find(variant)?.unwrap_or_default();
+                let deprecated = IndexAttribute::find_named(variant, "index_deprecated")?
+                    .unwrap_or_default()
+                    .values_for(None)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let since = attribute
+                    .expr_for(Some(&format_ident!("since")))
+                    .map(|expr| self.position_of(variant, expr))
+                    .transpose()?
+                    .unwrap_or(0);
+                let until = attribute
+                    .expr_for(Some(&format_ident!("until")))
+                    .map(|expr| self.position_of(variant, expr))
+                    .transpose()?
+                    .unwrap_or(self.0.len() - 1);
+
+                let is_present = (since..=until).contains(&position);
+
+                let variant_index = if !is_present {
+                    return Ok((variant, VariantIndex::skipped()));
+                } else if attribute.has_flag("skip") {
+                    VariantIndex::skipped()
+                } else {
+                    let expr = self.0[..=position]
+                        .iter()
+                        .rev()
+                        .find_map(|version| attribute.expr_for(Some(version)))
+                        .or_else(|| attribute.expr_for(None))
+                        .ok_or_else(|| {
+                            errors::EnumIndexError::MappingIndexNotGiven(
+                                variant.ident.to_string(),
+                                version.to_string(),
+                            )
+                        })?;
+
+                    if attribute.has_flag("deprecated") {
+                        VariantIndex::skipped().with_deprecated([expr.clone()])
+                    } else {
+                        VariantIndex::from_expr(expr.clone())
+                    }
+                };
+
+                Ok((variant, variant_index.with_deprecated(deprecated)))
+            })
+            .collect()
+    }
+}
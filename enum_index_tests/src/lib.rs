@@ -0,0 +1,2 @@
+//! Integration tests of `enum_index` that need heavy native dependencies; see the
+//! `tests` directory. This crate is not published.
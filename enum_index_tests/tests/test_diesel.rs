@@ -0,0 +1,117 @@
+//! Tests for the `diesel` implementations of the `diesel` feature, against an in-memory
+//! SQLite database.
+//!
+#![cfg(feature = "diesel")]
+extern crate proc_macro;
+
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{SmallInt, Text};
+use enum_index::prelude::*;
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(i16)]
+#[enum_index(diesel(sql_type = SmallInt))]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(String)]
+#[enum_index(diesel(sql_type = Text))]
+pub enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+diesel::table! {
+    tasks (id) {
+        id -> Integer,
+        priority -> SmallInt,
+        status -> Text,
+    }
+}
+
+#[derive(Queryable, Insertable, Debug, PartialEq)]
+#[diesel(table_name = tasks)]
+struct Task {
+    id: i32,
+    priority: Priority,
+    status: Status,
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.batch_execute(
+        "CREATE TABLE tasks (id INTEGER PRIMARY KEY, priority SMALLINT NOT NULL, \
+         status TEXT NOT NULL)",
+    )
+    .unwrap();
+    conn
+}
+
+#[test]
+fn test_round_trip() {
+    let mut conn = connect();
+
+    diesel::insert_into(tasks::table)
+        .values(&Task {
+            id: 1,
+            priority: Priority::High,
+            status: Status::Closed,
+        })
+        .execute(&mut conn)
+        .unwrap();
+
+    let raw: (i16, String) = tasks::table
+        .select((tasks::priority, tasks::status))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(raw, (5, "closed".to_string()));
+
+    let task: Task = tasks::table
+        .filter(tasks::priority.eq(Priority::High))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(task.status, Status::Closed);
+}
+
+#[test]
+fn test_unknown_index() {
+    let mut conn = connect();
+
+    conn.batch_execute("INSERT INTO tasks VALUES (1, 3, 'pending')")
+        .unwrap();
+
+    let err = tasks::table
+        .select(tasks::priority)
+        .first::<Priority>(&mut conn)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        diesel::result::Error::DeserializationError(_)
+    ));
+    assert!(format!("{:?}", err).contains("IndexNotFound(\"3\")"));
+}
+
+#[test]
+fn test_skipped_variant() {
+    let mut conn = connect();
+
+    let result = diesel::insert_into(tasks::table)
+        .values((
+            tasks::id.eq(1),
+            tasks::priority.eq(Priority::Unknown),
+            tasks::status.eq(Status::Open),
+        ))
+        .execute(&mut conn);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,80 @@
+//! Tests for the `pyo3` feature.
+//!
+#![cfg(feature = "pyo3")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(pyo3 = true)]
+enum Status {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(pyo3 = true)]
+enum Colour {
+    #[index("red")]
+    Red,
+    #[index("green")]
+    Green,
+}
+
+#[test]
+fn test_into_python() {
+    Python::with_gil(|py| {
+        assert_eq!(Status::Closed.into_py(py).extract::<u8>(py).unwrap(), 3);
+        assert_eq!(Status::Open.to_object(py).extract::<u8>(py).unwrap(), 1);
+        assert_eq!(
+            Colour::Green.to_object(py).extract::<String>(py).unwrap(),
+            "green"
+        );
+    });
+}
+
+#[test]
+fn test_from_python() {
+    Python::with_gil(|py| {
+        assert_eq!(
+            3.to_object(py).extract::<Status>(py).unwrap(),
+            Status::Closed
+        );
+        assert_eq!(
+            2.to_object(py).extract::<Status>(py).unwrap(),
+            Status::Closed
+        );
+        assert_eq!(
+            "red".to_object(py).extract::<Colour>(py).unwrap(),
+            Colour::Red
+        );
+    });
+}
+
+#[test]
+fn test_unknown_index_raises_value_error() {
+    Python::with_gil(|py| {
+        let err = 4.to_object(py).extract::<Status>(py).unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert_eq!(
+            err.value_bound(py).to_string(),
+            EnumIndexError::IndexNotFound("4".to_string()).to_string()
+        );
+
+        let err = "blue".to_object(py).extract::<Colour>(py).unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+
+        // Values of the wrong type are rejected by the index type as usual.
+        let err = "open".to_object(py).extract::<Status>(py).unwrap_err();
+        assert!(!err.is_instance_of::<PyValueError>(py));
+    });
+}
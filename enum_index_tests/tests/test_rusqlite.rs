@@ -0,0 +1,120 @@
+//! Tests for the `rusqlite` implementations of the `rusqlite` feature, against an
+//! in-memory SQLite database.
+//!
+#![cfg(feature = "rusqlite")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use rusqlite::types::{FromSql, FromSqlError, ValueRef};
+use rusqlite::Connection;
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(rusqlite = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(rusqlite = true)]
+pub enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+fn connect() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE tasks (priority INTEGER, status TEXT)", ())
+        .unwrap();
+    conn
+}
+
+#[test]
+fn test_round_trip() {
+    let conn = connect();
+
+    conn.execute(
+        "INSERT INTO tasks VALUES (?1, ?2)",
+        (Priority::High, Status::Closed),
+    )
+    .unwrap();
+
+    let raw: (i64, String) = conn
+        .query_row("SELECT priority, status FROM tasks", (), |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(raw, (5, "closed".to_string()));
+
+    let row: (Priority, Status) = conn
+        .query_row("SELECT priority, status FROM tasks", (), |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(row, (Priority::High, Status::Closed));
+}
+
+#[test]
+fn test_unknown_index() {
+    let conn = connect();
+
+    conn.execute("INSERT INTO tasks VALUES (3, 'pending')", ())
+        .unwrap();
+
+    let err = conn
+        .query_row("SELECT priority FROM tasks", (), |row| {
+            row.get::<_, Priority>(0)
+        })
+        .unwrap_err();
+
+    match err {
+        rusqlite::Error::FromSqlConversionFailure(_, _, err) => {
+            assert_eq!(
+                err.downcast_ref::<EnumIndexError>()
+                    .map(ToString::to_string),
+                Some(EnumIndexError::IndexNotFound("3".to_string()).to_string())
+            );
+        }
+        err => panic!("unexpected error: {:?}", err),
+    }
+
+    let err = conn
+        .query_row("SELECT status FROM tasks", (), |row| {
+            row.get::<_, Status>(0)
+        })
+        .unwrap_err();
+    assert!(matches!(err, rusqlite::Error::FromSqlConversionFailure(..)));
+}
+
+#[test]
+fn test_from_sql_error() {
+    let err = Priority::column_result(ValueRef::Integer(3)).unwrap_err();
+
+    assert!(matches!(err, FromSqlError::Other(_)));
+    assert_eq!(
+        err.to_string(),
+        EnumIndexError::IndexNotFound("3".to_string()).to_string()
+    );
+}
+
+#[test]
+fn test_skipped_variant() {
+    let conn = connect();
+
+    let err = conn
+        .execute(
+            "INSERT INTO tasks (priority) VALUES (?1)",
+            (Priority::Unknown,),
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, rusqlite::Error::ToSqlConversionFailure(_)));
+}
@@ -0,0 +1,108 @@
+//! Tests for the `sqlx` implementations of the `sqlx` feature, against an in-memory
+//! SQLite database.
+//!
+#![cfg(feature = "sqlx")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use sqlx::{Connection, Row, SqliteConnection};
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(i16)]
+#[enum_index(sqlx = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(sqlx = true)]
+pub enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(String)]
+#[enum_index(sqlx = true)]
+pub enum Colour {
+    #[index("red")]
+    Red,
+}
+
+async fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE tasks (priority SMALLINT, status TEXT, colour TEXT)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    conn
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_round_trip() {
+    let mut conn = connect().await;
+
+    sqlx::query("INSERT INTO tasks VALUES (?, ?, ?)")
+        .bind(Priority::High)
+        .bind(Status::Closed)
+        .bind(Colour::Red)
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let raw: (i16, String) = sqlx::query_as("SELECT priority, status FROM tasks")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(raw, (5, "closed".to_string()));
+
+    let row: (Priority, Status, Colour) =
+        sqlx::query_as("SELECT priority, status, colour FROM tasks")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+    assert_eq!(row, (Priority::High, Status::Closed, Colour::Red));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_unknown_index() {
+    let mut conn = connect().await;
+
+    sqlx::query("INSERT INTO tasks VALUES (3, 'pending', 'blue')")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT priority, status FROM tasks")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+
+    let err = row.try_get::<Priority, _>("priority").unwrap_err();
+    assert!(err.to_string().contains("the index `3` is not available"));
+
+    let err = row.try_get::<Status, _>("status").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("the index `\"pending\"` is not available"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_skipped_variant() {
+    let mut conn = connect().await;
+
+    let result = sqlx::query("INSERT INTO tasks (priority) VALUES (?)")
+        .bind(Priority::Unknown)
+        .execute(&mut conn)
+        .await;
+
+    assert!(result.is_err());
+}
@@ -21,9 +21,51 @@ pub enum EnumIndexError {
     #[error("`{0}` is not a valid literal for #[index(value)].")]
     IndexNotALiteral(String),
 
-    #[error("variant `{0}` was not provided a value. State a value for this variant with #[index(value)].")]
+    #[error("variant `{0}` was not provided a value. State a value for this variant with #[index(value)], or leave it out with #[index(skip)].")]
     IndexNotGiven(String),
 
+    #[error("variant `{0}` was not provided a value for the mapping `{1}`. State a value for this variant with #[index({1} = value)].")]
+    MappingIndexNotGiven(String, String),
+
+    #[error("the mapping `{1}` is not declared in #[index_type(...)] of Enum `{0}`.")]
+    MappingNotFound(String, String),
+
+    #[error("variant `{0}` refers to the version `{1}`, which is not declared in #[index_versions(...)].")]
+    VersionNotFound(String, String),
+
+    #[error("#[index_versions(...)] of Enum `{0}` requires an unnamed #[index_type(T)].")]
+    VersionsWithNamedMapping(String),
+
+    #[error("#[index_versions(...)] of Enum `{0}` cannot be combined with #[enum_index(auto = ...)]; state the index of each variant with #[index(value)] or #[index(v1 = value)].")]
+    VersionsWithAutoIndex(String),
+
+    #[error("the index of variant `{0}` must be a literal to be used by {1}.")]
+    IndexNotLiteral(String, String),
+
+    #[error("#[enum_index(proto_definition = true)] of Enum `{0}` requires #[index_type(i32)], the type of protobuf enum values.")]
+    ProtoIndexTypeNotI32(String),
+
+    #[error("#[enum_index(bytemuck = true)] of Enum `{0}` requires #[repr({1})], so that its discriminants are of the index type.")]
+    BytemuckReprMismatch(String, String),
+
+    #[error("variant `{1}` of Enum `{0}` has fields, which #[enum_index(bytemuck = true)] does not allow.")]
+    BytemuckVariantHasFields(String, String),
+
+    #[error("#[enum_index({1})] of Enum `{0}` requires the `{2}` feature of `enum_index`; enable it in Cargo.toml with `features = [\"{2}\"]`.")]
+    FeatureNotEnabled(String, String, String),
+
+    #[error("#[enum_index(rkyv = true)] of Enum `{0}` is not supported, as the archived type cannot have generics.")]
+    RkyvWithGenerics(String),
+
+    #[error("#[enum_index({1})] of Enum `{0}` requires at least one variant with an index, as there is no value to generate otherwise.")]
+    NoIndexToGenerate(String, String),
+
+    #[error("could not derive an implicit index for variant `{0}`: {1}. State a value for this variant with #[index(value)].")]
+    AutoIndexNotResolved(String, String),
+
+    #[error("variant `{0}` was given the metadata `{1}`, which is not declared. Declare its type on the Enum with #[index_meta_type({1} = T)].")]
+    MetaKeyNotDeclared(String, String),
+
     #[error("parsing of tokens for `{0}` failed during build: {1:?}")]
     SynParseError(String, syn::parse::Error),
 
@@ -31,6 +73,12 @@ pub enum EnumIndexError {
     #[error("the index `{0}` is not available in this Enum.")]
     IndexNotFound(String),
 
+    #[error("the variant name `{0}` is not available in this Enum.")]
+    NameNotFound(String),
+
+    #[error("the variant `{0}` is declared with #[index(skip)] and has no index.")]
+    VariantSkipped(String),
+
     #[error("unknown error occured.")]
     Unknown,
 }
@@ -10,6 +10,56 @@ mod traits;
 pub use traits::*;
 
 mod structs;
+#[allow(unused_imports)]
 pub use structs::*;
 
 pub mod serde;
+
+/// Re-exports of the optional integrations, for use by the generated code only.
+///
+/// These are kept out of the crate root, so that `use enum_index::*` does not conflict
+/// with the user's own dependency on the same crates.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "clap")]
+    pub use clap;
+
+    #[cfg(feature = "sqlx")]
+    pub use sqlx;
+
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+
+    #[cfg(feature = "diesel")]
+    pub use diesel;
+
+    #[cfg(feature = "diesel")]
+    pub use crate::structs::diesel_bound::DieselBound;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
+    #[cfg(feature = "utoipa")]
+    pub use utoipa;
+
+    #[cfg(any(feature = "schemars", feature = "utoipa"))]
+    pub use serde_json;
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    #[cfg(feature = "rkyv")]
+    pub use rkyv;
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    #[cfg(feature = "pyo3")]
+    pub use pyo3;
+
+    #[cfg(feature = "wasm")]
+    pub use wasm_bindgen;
+}
@@ -3,3 +3,7 @@ pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub mod de {
     pub use serde::de::Error;
 }
+
+pub mod ser {
+    pub use serde::ser::Error;
+}
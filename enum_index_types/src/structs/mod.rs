@@ -1,3 +1,6 @@
 //! Any structs definition will go here.
 //!
 //! These structs can be exported.
+
+#[cfg(feature = "diesel")]
+pub(crate) mod diesel_bound;
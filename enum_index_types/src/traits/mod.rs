@@ -7,3 +7,8 @@ pub use enum_index::*;
 
 mod variant_by_name;
 pub use variant_by_name::*;
+
+#[cfg(feature = "proptest")]
+mod near_miss;
+#[cfg(feature = "proptest")]
+pub use near_miss::*;
@@ -0,0 +1,80 @@
+//! Trait for generating invalid indices close to valid ones, for fuzzing.
+
+/// Values that are one small edit away from `self`.
+///
+/// These are used by `any_index_strategy()` to exercise the rejection of unknown indices
+/// with values that are likely to be mistaken for valid ones, such as off-by-one integers
+/// or strings with a different case. Any near miss that happens to be a valid index is
+/// filtered out by the caller.
+pub trait NearMiss
+where
+    Self: Sized,
+{
+    fn near_misses(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_near_miss_int {
+    ( $( $ty:ty ),* ) => {
+        $(
+        impl NearMiss for $ty {
+            fn near_misses(&self) -> Vec<Self> {
+                [self.checked_sub(1), self.checked_add(1)]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        )*
+    };
+}
+
+impl_near_miss_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl NearMiss for bool {
+    fn near_misses(&self) -> Vec<Self> {
+        vec![!self]
+    }
+}
+
+impl NearMiss for char {
+    fn near_misses(&self) -> Vec<Self> {
+        let code = *self as u32;
+
+        [code.checked_sub(1), code.checked_add(1)]
+            .into_iter()
+            .flatten()
+            .filter_map(char::from_u32)
+            .chain(self.to_uppercase().chain(self.to_lowercase()))
+            .filter(|c| c != self)
+            .collect()
+    }
+}
+
+impl NearMiss for String {
+    fn near_misses(&self) -> Vec<Self> {
+        let mut chars = self.chars();
+        chars.next_back();
+
+        vec![
+            chars.as_str().to_string(),
+            format!("{} ", self),
+            format!(" {}", self),
+            self.to_uppercase(),
+            self.to_lowercase(),
+        ]
+        .into_iter()
+        .filter(|s| s != self)
+        .collect()
+    }
+}
+
+impl<T: NearMiss> NearMiss for Option<T> {
+    fn near_misses(&self) -> Vec<Self> {
+        match self {
+            Some(value) => std::iter::once(None)
+                .chain(value.near_misses().into_iter().map(Some))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Detection of the enums of `#[derive(EnumIndex)]`.
+//!
+//! The attributes of these enums are parsed by `enum_index_syntax`, as in the derive
+//! macro itself.
+
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+/// Check if an enum has `#[derive(EnumIndex)]`, possibly with a path such as
+/// `#[derive(enum_index::EnumIndex)]`.
+pub fn is_derived(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "EnumIndex")
+        })
+}
@@ -0,0 +1,263 @@
+//! Listing of the `#[derive(EnumIndex)]` enums in Rust source files, for the
+//! `enum_index` binary.
+//!
+//! The indices are resolved by `enum_index_syntax`, the same as in the derive macro,
+//! including implicit indices from `#[enum_index(auto = "...")]`, so that the indices of
+//! a crate can be audited without reading or expanding its code. Indices are reported as
+//! written in the source, such as `"open"` or `LARGE`.
+
+use proc_macro2::LineColumn;
+use quote::ToTokens;
+use syn::visit::Visit;
+
+use enum_index::EnumIndexError;
+use enum_index_syntax::{
+    eval_int, AutoIndex, EnumIndexOptions, IndexMappings, IndexVersions, VariantIndex,
+};
+
+mod attributes;
+
+mod render;
+pub use render::{render_json, render_table};
+
+/// A column of indices: the unnamed mapping, a named mapping, or a version.
+pub struct Column {
+    pub name: String,
+    pub index_type: String,
+    /// Whether this column drives `index()` and `from_index()`.
+    pub primary: bool,
+}
+
+/// The indices of a single variant, one for each [`Column`].
+pub struct VariantReport {
+    pub name: String,
+    /// The index in each column, or [`None`] if the variant is skipped in that column.
+    pub indices: Vec<Option<String>>,
+    /// The retired indices in each column.
+    pub deprecated: Vec<Vec<String>>,
+}
+
+/// A single `#[derive(EnumIndex)]` enum.
+pub struct EnumReport {
+    pub file: String,
+    pub line: usize,
+    pub name: String,
+    pub columns: Vec<Column>,
+    pub variants: Vec<VariantReport>,
+}
+
+/// An enum that could not be resolved, such as one with a variant without an index.
+pub struct EnumError {
+    pub file: String,
+    pub line: usize,
+    pub name: String,
+    pub error: EnumIndexError,
+}
+
+/// Find every `#[derive(EnumIndex)]` enum in a source file, including those in inline
+/// modules and function bodies.
+pub fn inspect_source(file: &str, text: &str) -> syn::Result<Vec<Result<EnumReport, EnumError>>> {
+    let syntax = syn::parse_file(text)?;
+
+    let mut visitor = Visitor {
+        source: Source::new(text),
+        file,
+        reports: Vec::new(),
+    };
+    visitor.visit_file(&syntax);
+
+    Ok(visitor.reports)
+}
+
+struct Visitor<'a> {
+    source: Source<'a>,
+    file: &'a str,
+    reports: Vec<Result<EnumReport, EnumError>>,
+}
+impl<'ast> Visit<'ast> for Visitor<'_> {
+    fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
+        if attributes::is_derived(&item.attrs) {
+            let line = item.ident.span().start().line;
+            let name = item.ident.to_string();
+
+            self.reports.push(
+                resolve_enum(&self.source, item)
+                    .map(|(columns, variants)| EnumReport {
+                        file: self.file.to_string(),
+                        line,
+                        name: name.clone(),
+                        columns,
+                        variants,
+                    })
+                    .map_err(|error| EnumError {
+                        file: self.file.to_string(),
+                        line,
+                        name,
+                        error,
+                    }),
+            );
+        }
+
+        syn::visit::visit_item_enum(self, item);
+    }
+}
+
+/// The resolved index of a variant in a single column, as written in the source.
+struct Resolved {
+    index: Option<String>,
+    deprecated: Vec<String>,
+}
+impl Resolved {
+    fn new(source: &Source, variant_index: &VariantIndex) -> Self {
+        let index = variant_index
+            .expr()
+            .map(|expr| match variant_index.is_generated() {
+                // Implicit indices are not in the source; these are integer or `str` literals.
+                true => eval_int(expr).map_or_else(
+                    || expr.to_token_stream().to_string(),
+                    |value| value.to_string(),
+                ),
+                false => source.text(expr),
+            });
+
+        Self {
+            index,
+            deprecated: variant_index
+                .deprecated()
+                .iter()
+                .map(|expr| source.text(expr))
+                .collect(),
+        }
+    }
+}
+
+/// Resolve the columns of an enum, and the indices of every variant in each of them.
+fn resolve_enum(
+    source: &Source,
+    item: &syn::ItemEnum,
+) -> Result<(Vec<Column>, Vec<VariantReport>), EnumIndexError> {
+    let name = item.ident.to_string();
+    let input = syn::DeriveInput::from(item.clone());
+
+    let mappings = IndexMappings::try_from(&input)?;
+    let options = EnumIndexOptions::try_from(&input)?;
+    let primary = mappings.primary(&input, options.primary.as_ref())?;
+    let versions = IndexVersions::find(&input)?;
+
+    let resolve = |resolved: Vec<(&syn::Variant, VariantIndex)>| {
+        resolved
+            .iter()
+            .map(|(_, variant_index)| Resolved::new(source, variant_index))
+            .collect::<Vec<_>>()
+    };
+
+    let (columns, resolved) = match &versions {
+        Some(_) if primary.name.is_some() => {
+            return Err(EnumIndexError::VersionsWithNamedMapping(name))
+        }
+        Some(_) if options.auto.is_some() => {
+            return Err(EnumIndexError::VersionsWithAutoIndex(name))
+        }
+        Some(versions) => {
+            let index_type = source.text(&primary.params.specified);
+            let columns = versions
+                .iter()
+                .map(|version| Column {
+                    name: version.to_string(),
+                    index_type: index_type.clone(),
+                    primary: version == versions.latest(),
+                })
+                .collect::<Vec<_>>();
+            let resolved = versions
+                .iter()
+                .map(|version| versions.resolve(version, item.variants.iter()).map(resolve))
+                .collect::<Result<Vec<_>, _>>()?;
+            (columns, resolved)
+        }
+        None => {
+            let columns = mappings
+                .iter()
+                .map(|mapping| Column {
+                    name: mapping
+                        .name
+                        .as_ref()
+                        .map_or_else(|| "index".to_string(), ToString::to_string),
+                    index_type: source.text(&mapping.params.specified),
+                    primary: std::ptr::eq(mapping, primary),
+                })
+                .collect::<Vec<_>>();
+            let auto = options.auto_index();
+            let resolved = mappings
+                .iter()
+                .map(|mapping| {
+                    AutoIndex::resolve(auto.as_ref(), mapping.name.as_ref(), item.variants.iter())
+                        .map(resolve)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            (columns, resolved)
+        }
+    };
+
+    let variants = item
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(position, variant)| {
+            let (indices, deprecated) = resolved
+                .iter()
+                .map(|column| {
+                    let Resolved { index, deprecated } = &column[position];
+                    (index.clone(), deprecated.clone())
+                })
+                .unzip();
+
+            VariantReport {
+                name: variant.ident.to_string(),
+                indices,
+                deprecated,
+            }
+        })
+        .collect();
+
+    Ok((columns, variants))
+}
+
+/// The text of a source file, to recover syntax as it was written.
+struct Source<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+impl<'a> Source<'a> {
+    fn new(text: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+
+        Self { text, line_starts }
+    }
+
+    /// The byte offset of a line and column, the latter of which is counted in `char`s.
+    fn offset(&self, at: LineColumn) -> usize {
+        let line_start = self.line_starts[at.line - 1];
+        line_start
+            + self.text[line_start..]
+                .chars()
+                .take(at.column)
+                .map(char::len_utf8)
+                .sum::<usize>()
+    }
+
+    /// The source text of a piece of syntax, from its first token to its last.
+    fn text(&self, syntax: &impl ToTokens) -> String {
+        let mut tokens = syntax.to_token_stream().into_iter();
+        let first = tokens.next();
+        let last = tokens.last().or_else(|| first.clone());
+
+        match (first, last) {
+            (Some(first), Some(last)) => self.text
+                [self.offset(first.span().start())..self.offset(last.span().end())]
+                .to_string(),
+            _ => String::new(),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Output formats of the `enum_index` binary.
+
+use serde_json::{json, Value};
+
+use super::EnumReport;
+
+/// Render the enums as human readable tables, one after the other.
+///
+/// Each enum is headed by its name and location, followed by a row for each variant
+/// with its index in every column; skipped variants are shown as `-`. Retired indices
+/// are listed in a last column, if there are any.
+pub fn render_table(reports: &[EnumReport]) -> String {
+    reports
+        .iter()
+        .map(|report| {
+            let has_deprecated = report
+                .variants
+                .iter()
+                .any(|variant| variant.deprecated.iter().any(|values| !values.is_empty()));
+
+            let header = std::iter::once("variant".to_string())
+                .chain(report.columns.iter().map(|column| {
+                    if column.primary && report.columns.len() > 1 {
+                        format!("{}: {} (primary)", column.name, column.index_type)
+                    } else {
+                        format!("{}: {}", column.name, column.index_type)
+                    }
+                }))
+                .chain(has_deprecated.then(|| "deprecated".to_string()))
+                .collect::<Vec<_>>();
+
+            let rows = report.variants.iter().map(|variant| {
+                let deprecated = variant
+                    .deprecated
+                    .iter()
+                    .zip(&report.columns)
+                    .flat_map(|(values, column)| {
+                        values.iter().map(move |value| {
+                            if report.columns.len() > 1 {
+                                format!("{} = {}", column.name, value)
+                            } else {
+                                value.clone()
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                std::iter::once(variant.name.clone())
+                    .chain(
+                        variant
+                            .indices
+                            .iter()
+                            .map(|index| index.clone().unwrap_or_else(|| "-".to_string())),
+                    )
+                    .chain(has_deprecated.then_some(deprecated))
+                    .collect::<Vec<_>>()
+            });
+            let rows = std::iter::once(header).chain(rows).collect::<Vec<_>>();
+
+            let widths = (0..rows[0].len())
+                .map(|cell| {
+                    rows.iter()
+                        .map(|row| row[cell].chars().count())
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect::<Vec<_>>();
+            let lines = rows
+                .iter()
+                .map(|row| {
+                    let line = row
+                        .iter()
+                        .zip(&widths)
+                        .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                        .collect::<Vec<_>>()
+                        .join("  ");
+                    format!("    {}\n", line.trim_end())
+                })
+                .collect::<String>();
+
+            format!(
+                "{} ({}:{})\n{}",
+                report.name, report.file, report.line, lines
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render the enums as a JSON array.
+///
+/// The `indices` and `deprecated` of each variant are arrays in the same order as
+/// `columns`; a skipped variant has an index of `null`.
+pub fn render_json(reports: &[EnumReport]) -> Value {
+    reports
+        .iter()
+        .map(|report| {
+            json!({
+                "name": report.name,
+                "file": report.file,
+                "line": report.line,
+                "columns": report
+                    .columns
+                    .iter()
+                    .map(|column| json!({
+                        "name": column.name,
+                        "index_type": column.index_type,
+                        "primary": column.primary,
+                    }))
+                    .collect::<Vec<_>>(),
+                "variants": report
+                    .variants
+                    .iter()
+                    .map(|variant| json!({
+                        "name": variant.name,
+                        "indices": variant.indices,
+                        "deprecated": variant.deprecated,
+                    }))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
@@ -93,6 +93,12 @@
 //! - Each variant MUST have a `#[index()]` attribute, which contains a valid expression.
 //!   These indices must be unique.
 //!
+//!     - variants without an index can be declared with `#[index(skip)]` instead; these
+//!       are never returned by `from_index()`, and `try_index()` returns [`None`] for
+//!       them.
+//!     - missing indices can be filled in from the discriminant, the position or the
+//!       name of each variant with `#[enum_index(auto = "...")]`.
+//!
 //!     - the derive macro does not check the validity of this;
 //!
 //!         - in case of duplicated literal indices, a `match` condition conflict
@@ -103,6 +109,72 @@
 //!       possible.
 //!     - note that `String` type is a special case; you can declare the indices as
 //!       `str` literals without the need of `.to_owned()` or `.to_string()`.
+//!     - `&'static str` and `Cow<'static, str>` are also accepted as the index type;
+//!       these return the `str` literals without allocating, and still implement
+//!       [`From`] into [`String`].
+//!
+//! Generated items
+//! ---------------
+//!
+//! Besides the trait implementations, the derive macro adds the following `pub` items to
+//! the inherent `impl` block of the enum. These names are reserved: an enum that already
+//! defines an item of the same name fails to compile with a duplicate definition.
+//!
+//! - always: `index()`, `try_index()`, `from_index()`, `from_index_slice()`,
+//!   `is_deprecated_index()`, `variant_name()` and `description()`.
+//! - `INDEX_TABLE`, if every index can be evaluated in a `const` context.
+//! - `match_prefix()`, if every index is a `str` literal.
+//! - `{name}_index()`, `try_{name}_index()`, `from_{name}_index()`,
+//!   `from_{name}_index_slice()` and `is_deprecated_{name}_index()` for each mapping named
+//!   in `#[index_type(name = T)]`.
+//! - `index_for()` and `from_index_for()`, along with a `{Enum}Version` enum, with
+//!   `#[index_versions(...)]`.
+//! - one accessor per key of `#[index_meta_type(key = T)]`.
+//! - `proto_definition()`, `typescript_definition()` and `any_index_strategy()` with
+//!   `proto_definition = true`, `typescript_definition = true` and `proptest = true` in
+//!   `#[enum_index(...)]` respectively.
+//!
+//! `index()` and `from_index()` were private before version 0.2.0, and the other items did
+//! not exist; see `CHANGELOG.md`.
+//!
+//! Optional features
+//! -----------------
+//!
+//! - `arbitrary`: implements `arbitrary::Arbitrary` for enums declared with
+//!   `#[enum_index(arbitrary = true)]`.
+//! - `bytemuck`: implements `bytemuck::CheckedBitPattern` for enums declared with
+//!   `#[enum_index(bytemuck = true)]`.
+//! - `cli`: builds the `enum_index` binary, which lists the `#[derive(EnumIndex)]` enums
+//!   of a crate with their indices; install it with
+//!   `cargo install enum_index --features cli`.
+//! - `clap`: implements `clap::ValueEnum` for enums declared with
+//!   `#[enum_index(clap = "index" | "name")]`.
+//! - `diesel`: implements `ToSql`, `FromSql`, `AsExpression` and `FromSqlRow` for enums
+//!   declared with `#[enum_index(diesel(sql_type = T))]`.
+//! - `proptest`: implements `proptest::arbitrary::Arbitrary`, and generates
+//!   `any_index_strategy()` for valid and near-miss indices, for enums declared with
+//!   `#[enum_index(proptest = true)]`.
+//! - `pyo3`: implements `IntoPy`, `ToPyObject` and `FromPyObject` of `pyo3` through the
+//!   index, raising `ValueError` for unknown indices, for enums declared with
+//!   `#[enum_index(pyo3 = true)]`.
+//! - `rkyv`: implements `rkyv::Archive`, `rkyv::Serialize` and `rkyv::Deserialize`,
+//!   archiving the enum as its index, for enums declared with
+//!   `#[enum_index(rkyv = true)]`; unknown indices fail validation.
+//! - `rusqlite`: implements `rusqlite::ToSql` and `rusqlite::types::FromSql` through the
+//!   index, for enums declared with `#[enum_index(rusqlite = true)]`.
+//! - `schemars`: implements `schemars::JsonSchema` as the set of allowed indices, for
+//!   enums declared with `#[enum_index(schemars = true)]`.
+//! - `sqlx`: implements `sqlx::Type`, `sqlx::Encode` and `sqlx::Decode` through the
+//!   index, for any database that supports the index type, for enums declared with
+//!   `#[enum_index(sqlx = true)]`.
+//! - `utoipa`: implements `utoipa::PartialSchema` and `utoipa::ToSchema` as the set of
+//!   allowed indices, for enums declared with `#[enum_index(utoipa = true)]`.
+//! - `wasm`: implements the `wasm_bindgen` ABI traits and `From<T> for JsValue` through
+//!   the index, for enums declared with `#[enum_index(wasm = true)]`.
+//!
+//! Nothing is implemented for an enum unless it opts in, so that a feature enabled by
+//! any crate in the dependency graph does not affect the enums of other crates. Using an
+//! option without its feature fails to compile.
 //!
 pub mod prelude;
 
@@ -1,17 +1,141 @@
-extern crate proc_macro;
+//! Command line tool to list the `#[derive(EnumIndex)]` enums of a crate, along with
+//! their index types, variants and indices, so that the mappings can be audited without
+//! reading the code.
+//!
+//! ```text
+//! enum_index [--json] [PATH]...
+//! ```
+//!
+//! The binary is only built with the `cli` feature, so that library users do not compile
+//! its dependencies.
+//!
+//! Each `PATH` is either a Rust source file, or a directory which is searched for `.rs`
+//! files, leaving out `target` and hidden directories; the default is the current
+//! directory. Enums are listed as tables unless `--json` is given.
+//!
+//! Files that cannot be parsed and enums whose indices cannot be resolved are reported
+//! on stderr, in which case the exit code is `1`.
 
-use enum_index::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-#[derive(Debug, EnumIndex)]
-#[index_type(String)]
-pub enum MyEnum {
-    #[index("Member 0")]
-    MEMBER0,
-    #[index("Member 1")]
-    MEMBER1,
+mod inspect;
+
+const USAGE: &str = "\
+Usage: enum_index [--json] [PATH]...
+
+List the #[derive(EnumIndex)] enums in Rust source files, along with their index
+types, variants and indices.
+
+Arguments:
+  [PATH]...  Rust source files, or directories to search for them [default: .]
+
+Options:
+      --json  Print a JSON array instead of tables
+  -h, --help  Print this help
+";
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut paths = Vec::new();
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            flag if flag.starts_with('-') => {
+                eprint!("error: unknown option `{}`\n\n{}", flag, USAGE);
+                return ExitCode::from(2);
+            }
+            path => paths.push(PathBuf::from(path)),
+        }
+    }
+    if paths.is_empty() {
+        paths.push(PathBuf::from("."));
+    }
+
+    let mut has_errors = false;
+    let mut files = Vec::new();
+    for path in &paths {
+        if let Err(err) = find_sources(path, &mut files) {
+            eprintln!("error: {}: {}", path.display(), err);
+            has_errors = true;
+        }
+    }
+
+    let mut reports = Vec::new();
+    for file in &files {
+        let name = file.display().to_string();
+        let enums = std::fs::read_to_string(file)
+            .map_err(|err| err.to_string())
+            .and_then(|text| inspect::inspect_source(&name, &text).map_err(|err| err.to_string()));
+
+        match enums {
+            Ok(enums) => {
+                for report in enums {
+                    match report {
+                        Ok(report) => reports.push(report),
+                        Err(err) => {
+                            eprintln!(
+                                "error: {}:{}: enum `{}`: {}",
+                                err.file, err.line, err.name, err.error
+                            );
+                            has_errors = true;
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("error: {}: {}", name, err);
+                has_errors = true;
+            }
+        }
+    }
+
+    if json {
+        println!("{:#}", inspect::render_json(&reports));
+    } else {
+        print!("{}", inspect::render_table(&reports));
+    }
+
+    if has_errors {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
-fn main() {
-    println!("{}", MyEnum::MEMBER0.index());
-    println!("{:?}", MyEnum::try_from("Member 1"));
+/// Collect the `.rs` files at a path in a stable order, searching directories
+/// recursively.
+fn find_sources(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        // Files given explicitly are read as is, so that a missing file is reported.
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for entry in entries {
+        let name = entry
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        if entry.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                find_sources(&entry, files)?;
+            }
+        } else if entry.extension().is_some_and(|extension| extension == "rs") {
+            files.push(entry);
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,40 @@
+//! Tests for the `arbitrary::Arbitrary` implementation of the `arbitrary` feature.
+//!
+#![cfg(feature = "arbitrary")]
+extern crate proc_macro;
+
+use arbitrary::{Arbitrary, Unstructured};
+use enum_index::prelude::*;
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(arbitrary = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[test]
+fn test_arbitrary_round_trip() {
+    let data = (0..=255).collect::<Vec<u8>>();
+    let mut u = Unstructured::new(&data);
+
+    let mut seen = Vec::new();
+    while !u.is_empty() {
+        let priority = Priority::arbitrary(&mut u).unwrap();
+
+        assert_eq!(
+            Priority::from_index(&priority.index()).as_ref(),
+            Some(&priority)
+        );
+        if !seen.contains(&priority) {
+            seen.push(priority);
+        }
+    }
+
+    assert_eq!(seen.len(), 2);
+}
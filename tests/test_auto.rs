@@ -0,0 +1,81 @@
+//! Tests for implicit indices with `#[enum_index(auto = "...")]`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+const LARGE: i32 = 100;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(i32)]
+#[enum_index(auto = "discriminant")]
+#[repr(i32)]
+enum Discriminant {
+    Zero,
+    One,
+    Three = 3,
+    Four,
+    #[index(40)]
+    Explicit,
+    Negative = -2,
+    AfterNegative,
+    Large = LARGE,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u32)]
+#[enum_index(auto = "position", start = 1, step = 10)]
+enum Position {
+    First,
+    #[index(0)]
+    Second,
+    Third,
+    #[index(skip)]
+    Fourth,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(auto = "name")]
+enum Name {
+    Open,
+    #[index("shut")]
+    Closed,
+}
+
+#[test]
+fn test_discriminant() {
+    assert_eq!(Discriminant::Zero.index(), 0);
+    assert_eq!(Discriminant::One.index(), 1);
+    assert_eq!(Discriminant::Three.index(), 3);
+    assert_eq!(Discriminant::Four.index(), 4);
+    assert_eq!(Discriminant::Explicit.index(), 40);
+    assert_eq!(Discriminant::Negative.index(), -2);
+    assert_eq!(Discriminant::AfterNegative.index(), -1);
+    assert_eq!(Discriminant::Large.index(), LARGE);
+
+    assert_eq!(Discriminant::from_index(&4), Some(Discriminant::Four));
+    assert_eq!(
+        Discriminant::from_index(&-1),
+        Some(Discriminant::AfterNegative)
+    );
+    assert_eq!(Discriminant::from_index(&LARGE), Some(Discriminant::Large));
+}
+
+#[test]
+fn test_position() {
+    assert_eq!(Position::First.index(), 1);
+    assert_eq!(Position::Second.index(), 0);
+    assert_eq!(Position::Third.index(), 21);
+    assert_eq!(Position::Fourth.try_index(), None);
+    assert_eq!(Position::from_index(&21), Some(Position::Third));
+    assert_eq!(Position::from_index(&31), None);
+}
+
+#[test]
+fn test_name() {
+    assert_eq!(Name::Open.index(), "Open");
+    assert_eq!(Name::Closed.index(), "shut");
+    assert_eq!(Name::try_from("Open").unwrap(), Name::Open);
+    assert_eq!(Name::match_prefix("Opened"), Some((Name::Open, 4)));
+}
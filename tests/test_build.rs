@@ -1,3 +1,4 @@
+#![allow(clippy::empty_docs)]
 ///
 ///
 extern crate proc_macro;
@@ -207,3 +208,23 @@ test_string_factory!(
     (Member18, "rgU79h6+jzLlrxMDkg=="),
     (Member19, "VWFeecoRdYVPmzUX1g=="),
 );
+
+test_string_factory!(
+    test_static_str,
+    &'static str,
+    "Invalid String",
+    (Member00, "<="),
+    (Member01, "<"),
+    (Member02, "="),
+    (Member03, ""),
+);
+
+test_string_factory!(
+    test_cow_str,
+    std::borrow::Cow<'static, str>,
+    "Invalid String",
+    (Member00, "<="),
+    (Member01, "<"),
+    (Member02, "="),
+    (Member03, ""),
+);
@@ -0,0 +1,76 @@
+//! Tests for `#[enum_index(bytemuck = true)]` with the `bytemuck` feature.
+//!
+#![cfg(feature = "bytemuck")]
+extern crate proc_macro;
+
+use bytemuck::checked::{self, CheckedCastError};
+use enum_index::prelude::*;
+
+#[derive(Clone, Copy, Debug, EnumIndex, PartialEq)]
+#[index_type(u16)]
+#[enum_index(bytemuck = true)]
+#[repr(u16)]
+enum Status {
+    #[index(1)]
+    Open = 1,
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed = 3,
+    #[index(skip)]
+    Unknown = 0,
+}
+
+#[derive(Clone, Copy, Debug, EnumIndex, PartialEq)]
+#[index_type(i8)]
+#[enum_index(auto = "discriminant", bytemuck = true)]
+#[repr(i8)]
+enum Level {
+    Low = -1,
+    Normal,
+    High,
+}
+
+#[test]
+fn test_cast_slice() {
+    let bits: [u16; 3] = [3, 1, 3];
+    assert_eq!(
+        checked::try_cast_slice::<u16, Status>(&bits).unwrap(),
+        [Status::Closed, Status::Open, Status::Closed]
+    );
+
+    let bits: [i8; 3] = [0, -1, 1];
+    assert_eq!(
+        checked::try_cast_slice::<i8, Level>(&bits).unwrap(),
+        [Level::Normal, Level::Low, Level::High]
+    );
+}
+
+#[test]
+fn test_invalid_bit_pattern() {
+    // Unknown indices and the discriminant of a skipped variant are rejected, as by
+    // `from_index()`.
+    for bits in [4u16, 0] {
+        assert_eq!(
+            checked::try_cast::<u16, Status>(bits),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+    }
+    assert_eq!(
+        checked::try_cast_slice::<i8, Level>(&[0, 2]),
+        Err(CheckedCastError::InvalidBitPattern)
+    );
+}
+
+#[test]
+fn test_retired_bit_pattern() {
+    // Unlike `from_index()`, a retired index is not a discriminant, hence cannot be cast.
+    assert_eq!(Status::from_index(&2), Some(Status::Closed));
+    assert_eq!(
+        checked::try_cast::<u16, Status>(2),
+        Err(CheckedCastError::InvalidBitPattern)
+    );
+    assert_eq!(
+        Status::from_index_slice(&[2, 3]).unwrap(),
+        [Status::Closed, Status::Closed]
+    );
+}
@@ -0,0 +1,44 @@
+//! Tests for `#[cfg]` and `#[cfg_attr]` on variants.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(display = "name", from_str = "both")]
+enum Feature {
+    #[index("on")]
+    On,
+    #[cfg(not(test))]
+    #[index("off")]
+    Off,
+    #[cfg_attr(test, cfg(not(test)))]
+    #[index("o")]
+    Hidden,
+    #[cfg(test)]
+    #[index("onward")]
+    Onward,
+}
+
+#[test]
+fn test_enabled_variants() {
+    assert_eq!(Feature::On.index(), "on");
+    assert_eq!(Feature::Onward.index(), "onward");
+    assert_eq!(Feature::from_index("onward"), Some(Feature::Onward));
+    assert_eq!(Feature::by_name("Onward"), Some(Feature::Onward));
+    assert_eq!(Feature::Onward.to_string(), "Onward");
+}
+
+#[test]
+fn test_disabled_variants() {
+    assert_eq!(Feature::from_index("off"), None);
+    assert_eq!(Feature::from_index("o"), None);
+    assert_eq!(Feature::by_name("Off"), None);
+    assert_eq!(Feature::by_name("Hidden"), None);
+    assert!("Hidden".parse::<Feature>().is_err());
+
+    assert_eq!(Feature::match_prefix("off"), None);
+    assert_eq!(Feature::match_prefix("onwards"), Some((Feature::Onward, 6)));
+    assert_eq!(Feature::match_prefix("one"), Some((Feature::On, 2)));
+}
@@ -0,0 +1,96 @@
+//! Tests for the `clap::ValueEnum` implementation of the `clap` feature.
+//!
+#![cfg(feature = "clap")]
+extern crate proc_macro;
+
+use clap::ValueEnum;
+use enum_index::prelude::*;
+
+#[derive(EnumIndex, Clone, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(clap = "index")]
+pub enum Format {
+    /// Human readable table.
+    #[index("table")]
+    Table,
+    /// Machine readable JSON.
+    #[index("json")]
+    #[index_deprecated("js")]
+    Json,
+    #[index(skip)]
+    Internal,
+}
+
+#[derive(EnumIndex, Clone, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(clap = "name")]
+pub enum LogLevel {
+    #[index(1)]
+    ErrorOnly,
+    #[index(2)]
+    WARNLevel,
+    #[index(3)]
+    Info,
+}
+
+#[derive(EnumIndex, Clone, Debug, PartialEq)]
+#[index_type(i8)]
+#[enum_index(clap = "index")]
+pub enum Code {
+    #[index(10)]
+    Ten,
+    #[index(-1)]
+    MinusOne,
+}
+
+#[derive(EnumIndex, Clone, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(clap = "name", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Shouty {
+    #[index(1)]
+    HTTPServer,
+    #[index(2)]
+    Ipv4Address,
+}
+
+#[test]
+fn test_index_values() {
+    assert_eq!(Format::value_variants(), &[Format::Table, Format::Json]);
+    assert_eq!(Format::from_str("table", false), Ok(Format::Table));
+    assert_eq!(Format::from_str("JS", true), Ok(Format::Json));
+    assert!(Format::from_str("Table", false).is_err());
+    assert!(Format::Internal.to_possible_value().is_none());
+
+    let json = Format::Json.to_possible_value().unwrap();
+    assert_eq!(json.get_name(), "json");
+    assert_eq!(
+        json.get_help().map(ToString::to_string),
+        Some("Machine readable JSON.".to_string())
+    );
+
+    assert_eq!(Code::from_str("10", false), Ok(Code::Ten));
+    assert_eq!(Code::from_str("-1", false), Ok(Code::MinusOne));
+}
+
+#[test]
+fn test_name_values() {
+    let names = LogLevel::value_variants()
+        .iter()
+        .map(|level| level.to_possible_value().unwrap().get_name().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, ["error-only", "warn-level", "info"]);
+    assert_eq!(
+        LogLevel::from_str("warn-level", false),
+        Ok(LogLevel::WARNLevel)
+    );
+
+    assert_eq!(
+        Shouty::from_str("HTTP_SERVER", false),
+        Ok(Shouty::HTTPServer)
+    );
+    assert_eq!(
+        Shouty::from_str("IPV4_ADDRESS", false),
+        Ok(Shouty::Ipv4Address)
+    );
+}
@@ -0,0 +1,65 @@
+//! Tests for retired indices with `#[index(value, deprecated)]` and
+//! `#[index_deprecated(...)]`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(skipped = "option")]
+enum Code {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    #[index_deprecated(7, 8)]
+    Closed,
+    #[index(9, deprecated)]
+    Legacy,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+enum Operator {
+    #[index("!=")]
+    #[index_deprecated("<>")]
+    NotEqual,
+    #[index("<")]
+    LessThan,
+}
+
+#[test]
+fn test_deprecated_decodes() {
+    assert_eq!(Code::from_index(&3), Some(Code::Closed));
+    assert_eq!(Code::from_index(&7), Some(Code::Closed));
+    assert_eq!(Code::from_index(&8), Some(Code::Closed));
+    assert_eq!(Code::from_index(&9), Some(Code::Legacy));
+    assert_eq!(serde_json::from_str::<Code>("7").unwrap(), Code::Closed);
+}
+
+#[test]
+fn test_deprecated_never_emitted() {
+    assert_eq!(Code::Closed.index(), Some(3));
+    assert_eq!(Code::Legacy.index(), None);
+    assert!(serde_json::to_string(&Code::Legacy).is_err());
+}
+
+#[test]
+fn test_is_deprecated_index() {
+    assert!(Code::is_deprecated_index(&7));
+    assert!(Code::is_deprecated_index(&9));
+    assert!(!Code::is_deprecated_index(&3));
+    assert!(!Code::is_deprecated_index(&1));
+    assert!(!Code::is_deprecated_index(&100));
+}
+
+#[test]
+fn test_deprecated_str() {
+    assert_eq!(Operator::try_from("<>").unwrap(), Operator::NotEqual);
+    assert!(Operator::is_deprecated_index("<>"));
+    assert_eq!(
+        Operator::match_prefix("<> 1"),
+        Some((Operator::NotEqual, 2))
+    );
+    assert_eq!(Operator::match_prefix("< 1"), Some((Operator::LessThan, 1)));
+}
@@ -0,0 +1,58 @@
+//! Tests for `description()` and `INDEX_TABLE` built from variant doc comments.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+enum Status {
+    /// The ticket is open.
+    #[index(1)]
+    Open,
+    /// The ticket is closed.
+    ///
+    /// It cannot be reopened.
+    #[index(3)]
+    Closed,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(String)]
+enum Colour {
+    /// Pure red.
+    #[index("#FF0000")]
+    Red,
+}
+
+#[test]
+fn test_description() {
+    assert_eq!(Status::Open.description(), Some("The ticket is open."));
+    assert_eq!(
+        Status::Closed.description(),
+        Some("The ticket is closed.\n\nIt cannot be reopened.")
+    );
+    assert_eq!(Status::Unknown.description(), None);
+}
+
+#[test]
+fn test_index_table() {
+    assert_eq!(
+        Status::INDEX_TABLE,
+        &[
+            ("Open", Some(1), Some("The ticket is open.")),
+            (
+                "Closed",
+                Some(3),
+                Some("The ticket is closed.\n\nIt cannot be reopened.")
+            ),
+            ("Unknown", None, None),
+        ]
+    );
+    assert_eq!(
+        Colour::INDEX_TABLE,
+        &[("Red", Some("#FF0000"), Some("Pure red."))]
+    );
+}
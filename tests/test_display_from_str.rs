@@ -0,0 +1,74 @@
+//! Tests for `Display` and `FromStr` generated by `#[enum_index(...)]`.
+//!
+extern crate proc_macro;
+
+use std::str::FromStr;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(display = "index", from_str = "both")]
+enum Code {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    Closed,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(display = "name")]
+#[enum_index(from_str = "index")]
+enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(String)]
+#[enum_index(from_str = "name")]
+enum Colour {
+    #[index("#FF0000")]
+    Red,
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Code::Closed.to_string(), "3");
+    assert_eq!(Status::Closed.to_string(), "Closed");
+}
+
+#[test]
+fn test_from_str_index() {
+    assert_eq!(Status::from_str("open").unwrap(), Status::Open);
+    assert!(matches!(
+        Status::from_str("Open"),
+        Err(EnumIndexError::IndexNotFound(_))
+    ));
+}
+
+#[test]
+fn test_from_str_name() {
+    assert_eq!("Red".parse::<Colour>().unwrap(), Colour::Red);
+    assert!(matches!(
+        "#FF0000".parse::<Colour>(),
+        Err(EnumIndexError::NameNotFound(_))
+    ));
+}
+
+#[test]
+fn test_from_str_both() {
+    assert_eq!("3".parse::<Code>().unwrap(), Code::Closed);
+    assert_eq!("Open".parse::<Code>().unwrap(), Code::Open);
+    assert!(matches!(
+        "2".parse::<Code>(),
+        Err(EnumIndexError::IndexNotFound(_))
+    ));
+    assert!(matches!(
+        "Shut".parse::<Code>(),
+        Err(EnumIndexError::IndexNotFound(_))
+    ));
+}
@@ -0,0 +1,71 @@
+//! Tests for `#[derive(EnumIndex)]` on enums with type and lifetime parameters.
+//!
+extern crate proc_macro;
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u16)]
+#[enum_index(display = "name", from_str = "both")]
+enum Typed<T>
+where
+    T: Debug,
+{
+    #[index(1)]
+    Open,
+    #[index(3)]
+    Closed,
+    #[index(0)]
+    Marker(PhantomData<T>),
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+enum Borrowed<'a, T: Debug> {
+    #[index("open")]
+    Open,
+    #[index("marker")]
+    Marker { _marker: PhantomData<&'a T> },
+}
+
+#[test]
+fn test_type_params() {
+    assert_eq!(Typed::<String>::Closed.index(), 3);
+    assert_eq!(Typed::<String>::from_index(&1), Some(Typed::Open));
+    assert_eq!(
+        Typed::<String>::from_index(&0),
+        Some(Typed::Marker(PhantomData))
+    );
+    assert_eq!(u16::from(Typed::<String>::Closed), 3);
+    assert!(Typed::<String>::try_from(&2).is_err());
+    assert_eq!(Typed::<String>::by_name("Closed"), Some(Typed::Closed));
+
+    assert_eq!(Typed::<String>::Open.to_string(), "Open");
+    assert_eq!("3".parse::<Typed<String>>().unwrap(), Typed::Closed);
+
+    let serialized = serde_json::to_string(&Typed::<String>::Closed).unwrap();
+    assert_eq!(serialized, "3");
+    assert_eq!(
+        serde_json::from_str::<Typed<String>>(&serialized).unwrap(),
+        Typed::Closed
+    );
+}
+
+#[test]
+fn test_lifetime_params() {
+    assert_eq!(Borrowed::<'_, u8>::Open.index(), "open");
+    assert_eq!(
+        Borrowed::<'_, u8>::try_from("marker").unwrap(),
+        Borrowed::Marker {
+            _marker: PhantomData
+        }
+    );
+    assert_eq!(String::from(Borrowed::<'_, u8>::Open), "open");
+    assert_eq!(
+        serde_json::from_str::<Borrowed<'_, u8>>("\"open\"").unwrap(),
+        Borrowed::Open
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests for `from_index_slice()`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+enum Status {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed,
+}
+
+// A dense table: the indices, retired ones included, cover `-1..=2` without gaps.
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(i8)]
+enum Level {
+    #[index(-1)]
+    Debug,
+    #[index(0)]
+    #[index_deprecated(2)]
+    Info,
+    #[index(1)]
+    Warn,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(wire = u16, db = &'static str)]
+enum Colour {
+    #[index(wire = 10, db = "red")]
+    Red,
+    #[index(wire = 20, db = "green")]
+    Green,
+}
+
+#[test]
+fn test_from_index_slice() {
+    assert_eq!(
+        Status::from_index_slice(&[3, 1, 2]).unwrap(),
+        vec![Status::Closed, Status::Open, Status::Closed]
+    );
+    assert_eq!(Status::from_index_slice(&[]).unwrap(), vec![]);
+
+    assert_eq!(
+        Colour::from_db_index_slice(&["green", "red"]).unwrap(),
+        vec![Colour::Green, Colour::Red]
+    );
+}
+
+#[test]
+fn test_from_index_slice_error() {
+    let (position, err) = Status::from_index_slice(&[1, 3, 4, 5]).unwrap_err();
+    assert_eq!(position, 2);
+    assert!(matches!(err, EnumIndexError::IndexNotFound(index) if index == "4"));
+
+    let (position, _) = Colour::from_wire_index_slice(&[10, 30]).unwrap_err();
+    assert_eq!(position, 1);
+}
+
+#[test]
+fn test_from_index_slice_dense() {
+    assert_eq!(
+        Level::from_index_slice(&[1, -1, 2, 0]).unwrap(),
+        vec![Level::Warn, Level::Debug, Level::Info, Level::Info]
+    );
+    assert_eq!(Level::from_index_slice(&[]).unwrap(), vec![]);
+
+    let (position, err) = Level::from_index_slice(&[0, 1, 3, -2]).unwrap_err();
+    assert_eq!(position, 2);
+    assert!(matches!(err, EnumIndexError::IndexNotFound(index) if index == "3"));
+
+    let (position, _) = Level::from_index_slice(&[-2]).unwrap_err();
+    assert_eq!(position, 0);
+}
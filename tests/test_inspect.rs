@@ -0,0 +1,132 @@
+//! Tests for the `enum_index` binary, run against the enums of other tests.
+//!
+#![cfg(feature = "cli")]
+use std::process::Command;
+
+use serde_json::{json, Value};
+
+/// Run the binary, returning its exit code and stdout.
+fn run(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_enum_index"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+/// Find an enum by name in the JSON output.
+fn find<'a>(enums: &'a Value, name: &str) -> &'a Value {
+    enums
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["name"] == name)
+        .unwrap()
+}
+
+#[test]
+fn test_json_auto() {
+    let (code, stdout) = run(&["--json", "tests/test_auto.rs"]);
+    assert_eq!(code, Some(0));
+    let enums: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(enums.as_array().unwrap().len(), 3);
+
+    let position = find(&enums, "Position");
+    assert_eq!(position["file"], "tests/test_auto.rs");
+    assert_eq!(
+        position["columns"],
+        json!([{"name": "index", "index_type": "u32", "primary": true}])
+    );
+    assert_eq!(
+        position["variants"][0],
+        json!({"name": "First", "indices": ["1"], "deprecated": [[]]})
+    );
+    assert_eq!(position["variants"][2]["indices"], json!(["21"]));
+    assert_eq!(position["variants"][3]["indices"], json!([null]));
+
+    let discriminant = find(&enums, "Discriminant");
+    assert_eq!(discriminant["variants"][6]["indices"], json!(["-1"]));
+    assert_eq!(discriminant["variants"][7]["indices"], json!(["LARGE"]));
+
+    let name = find(&enums, "Name");
+    assert_eq!(name["columns"][0]["index_type"], "&'static str");
+    assert_eq!(name["variants"][0]["indices"], json!(["\"Open\""]));
+}
+
+#[test]
+fn test_json_mappings_and_versions() {
+    let (_, stdout) = run(&["--json", "tests/test_mappings.rs", "tests/test_versions.rs"]);
+    let enums: Value = serde_json::from_str(&stdout).unwrap();
+
+    let status = enums
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["name"] == "Status" && e["file"] == "tests/test_versions.rs")
+        .unwrap();
+    let columns = status["columns"].as_array().unwrap();
+    assert_eq!(columns.len(), 3);
+    assert_eq!(
+        columns[2],
+        json!({"name": "v3", "index_type": "u8", "primary": true})
+    );
+    assert_eq!(status["variants"][1]["indices"], json!(["3", "30", "30"]));
+}
+
+#[test]
+fn test_table() {
+    let (code, stdout) = run(&["tests/test_deprecated.rs"]);
+    assert_eq!(code, Some(0));
+    assert!(stdout.starts_with("Code (tests/test_deprecated.rs:11)\n"));
+    assert!(stdout.contains("    variant  index: u8  deprecated\n"));
+    assert!(stdout.contains("    Closed   3          7, 8\n"));
+    assert!(stdout.contains("    Legacy   -          9\n"));
+}
+
+#[test]
+fn test_unresolved_enum() {
+    let path = std::env::temp_dir().join(format!("enum_index_{}.rs", std::process::id()));
+    std::fs::write(
+        &path,
+        "#[derive(EnumIndex)]\n#[index_type(u8)]\nenum Broken {\n    #[index(1)]\n    A,\n    B,\n}\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_enum_index"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("enum `Broken`: variant `B` was not provided a value."));
+}
+
+#[test]
+fn test_non_literal_start() {
+    let path = std::env::temp_dir().join(format!("enum_index_start_{}.rs", std::process::id()));
+    std::fs::write(
+        &path,
+        "#[derive(EnumIndex)]\n#[index_type(u8)]\n#[enum_index(auto = \"position\", start = OFFSET)]\nenum Offset {\n    A,\n    B,\n}\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_enum_index"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // Rejected as by the derive macro, rather than listed from `start = 0`.
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("enum `Offset`:"));
+    assert!(stderr.contains("expected integer literal"));
+}
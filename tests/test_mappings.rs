@@ -0,0 +1,67 @@
+//! Tests for multiple named mappings with `#[index_type(name = T, ...)]`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(wire = u16, db = &'static str)]
+enum Status {
+    #[index(wire = 1, db = "open")]
+    Open,
+    #[index(wire = 3, db = "cancelled")]
+    Cancelled,
+    #[index(wire = 4, db = skip)]
+    Pending,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(wire = u16, db = String)]
+#[enum_index(primary = "db", from_str = "index")]
+enum DbStatus {
+    #[index(wire = 1, db = "open")]
+    Open,
+    #[index(wire = 3, db = "cancelled")]
+    Cancelled,
+}
+
+#[test]
+fn test_named_methods() {
+    assert_eq!(Status::Cancelled.wire_index(), 3);
+    assert_eq!(Status::Cancelled.db_index(), "cancelled");
+    assert_eq!(Status::from_wire_index(&3), Some(Status::Cancelled));
+    assert_eq!(Status::from_db_index("open"), Some(Status::Open));
+
+    assert_eq!(Status::Pending.try_wire_index(), Some(4));
+    assert_eq!(Status::Pending.try_db_index(), None);
+    assert_eq!(Status::from_db_index("pending"), None);
+}
+
+#[test]
+fn test_first_mapping_is_primary() {
+    assert_eq!(Status::Cancelled.index(), 3);
+    assert_eq!(Status::from_index(&1), Some(Status::Open));
+    assert_eq!(u16::from(Status::Open), 1);
+    assert_eq!(Status::try_from(&4).unwrap(), Status::Pending);
+    assert_eq!(serde_json::to_string(&Status::Cancelled).unwrap(), "3");
+}
+
+#[test]
+fn test_selected_primary() {
+    assert_eq!(DbStatus::Cancelled.index(), "cancelled");
+    assert_eq!(DbStatus::Cancelled.wire_index(), 3);
+    assert_eq!(String::from(DbStatus::Open), "open");
+    assert_eq!(
+        DbStatus::try_from("cancelled").unwrap(),
+        DbStatus::Cancelled
+    );
+    assert_eq!("open".parse::<DbStatus>().unwrap(), DbStatus::Open);
+    assert_eq!(DbStatus::match_prefix("opened"), Some((DbStatus::Open, 4)));
+
+    let serialized = serde_json::to_string(&DbStatus::Cancelled).unwrap();
+    assert_eq!(serialized, "\"cancelled\"");
+    assert_eq!(
+        serde_json::from_str::<DbStatus>(&serialized).unwrap(),
+        DbStatus::Cancelled
+    );
+}
@@ -0,0 +1,68 @@
+//! Tests for `match_prefix()` on `str` indices.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+enum Operator {
+    #[index("<=")]
+    LessThanOrEqual,
+    #[index("<")]
+    LessThan,
+    #[index("=")]
+    Equal,
+    #[index("==")]
+    StrictEqual,
+    #[index("===")]
+    Identical,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(String)]
+enum Greeting {
+    #[index("")]
+    Nothing,
+    #[index("héllo")]
+    Hello,
+}
+
+#[test]
+fn test_longest_match() {
+    assert_eq!(
+        Operator::match_prefix("<= 3"),
+        Some((Operator::LessThanOrEqual, 2))
+    );
+    assert_eq!(Operator::match_prefix("< 3"), Some((Operator::LessThan, 1)));
+    assert_eq!(Operator::match_prefix("<"), Some((Operator::LessThan, 1)));
+    assert_eq!(Operator::match_prefix("=3"), Some((Operator::Equal, 1)));
+    assert_eq!(
+        Operator::match_prefix("==3"),
+        Some((Operator::StrictEqual, 2))
+    );
+    assert_eq!(
+        Operator::match_prefix("===="),
+        Some((Operator::Identical, 3))
+    );
+}
+
+#[test]
+fn test_no_match() {
+    assert_eq!(Operator::match_prefix(""), None);
+    assert_eq!(Operator::match_prefix(" <="), None);
+    assert_eq!(Operator::match_prefix(">="), None);
+}
+
+#[test]
+fn test_multibyte_and_empty_index() {
+    let input = "héllo world";
+    let (variant, len) = Greeting::match_prefix(input).unwrap();
+    assert_eq!(variant, Greeting::Hello);
+    assert_eq!(&input[len..], " world");
+
+    assert_eq!(
+        Greeting::match_prefix("hello"),
+        Some((Greeting::Nothing, 0))
+    );
+}
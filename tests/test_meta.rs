@@ -0,0 +1,37 @@
+//! Tests for static metadata with `#[index_meta_type(...)]` and `#[index_meta(...)]`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[index_meta_type(label = &'static str, color = u32, deprecated = bool)]
+enum Status {
+    #[index(1)]
+    #[index_meta(label = "Open", color = 0x00FF00)]
+    Open,
+    #[index(3)]
+    #[index_meta(label = "Closed", color = 0xFF0000, deprecated = true)]
+    Closed,
+    #[index(4)]
+    Unlabelled,
+}
+
+#[test]
+fn test_meta_accessors() {
+    assert_eq!(Status::Open.label(), "Open");
+    assert_eq!(Status::Open.color(), 0x00FF00);
+    assert!(!Status::Open.deprecated());
+
+    assert_eq!(Status::Closed.label(), "Closed");
+    assert_eq!(Status::Closed.color(), 0xFF0000);
+    assert!(Status::Closed.deprecated());
+}
+
+#[test]
+fn test_meta_defaults() {
+    assert_eq!(Status::Unlabelled.label(), "");
+    assert_eq!(Status::Unlabelled.color(), 0);
+    assert!(!Status::Unlabelled.deprecated());
+}
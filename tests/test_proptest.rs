@@ -0,0 +1,71 @@
+//! Tests for the `proptest` strategies of the `proptest` feature.
+//!
+#![cfg(feature = "proptest")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use proptest::prelude::*;
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(proptest = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    #[index_deprecated(3)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(proptest = true)]
+pub enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+proptest! {
+    #[test]
+    fn test_round_trip(priority in any::<Priority>()) {
+        let decoded = Priority::from_index(&priority.index());
+        prop_assert_eq!(decoded.as_ref(), Some(&priority));
+        prop_assert_ne!(priority, Priority::Unknown);
+    }
+
+    #[test]
+    fn test_serde_round_trip(status in any::<Status>()) {
+        let json = serde_json::to_string(&status).unwrap();
+        prop_assert_eq!(serde_json::from_str::<Status>(&json).unwrap(), status);
+    }
+
+    #[test]
+    fn test_any_index(index in Priority::any_index_strategy()) {
+        let expected = [1, 3, 5].contains(&index);
+        prop_assert_eq!(Priority::from_index(&index).is_some(), expected);
+    }
+
+    #[test]
+    fn test_any_str_index(index in Status::any_index_strategy()) {
+        let expected = ["open", "closed"].contains(&index.as_str());
+        prop_assert_eq!(Status::try_from(index.as_str()).is_ok(), expected);
+    }
+}
+
+#[test]
+fn test_near_misses() {
+    assert_eq!(5u8.near_misses(), vec![4, 6]);
+    assert_eq!(0u8.near_misses(), vec![1]);
+    assert_eq!(
+        "open".to_string().near_misses(),
+        vec!["ope", "open ", " open", "OPEN"]
+    );
+    assert_eq!(
+        Some('a').near_misses(),
+        vec![None, Some('`'), Some('b'), Some('A')]
+    );
+}
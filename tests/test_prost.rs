@@ -0,0 +1,86 @@
+//! Tests for conversions to and from `prost` generated enums, and for
+//! `proto_definition()`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+/// As generated by `prost-build` from `enum Status { STATUS_UNSPECIFIED = 0; ... }`.
+mod pb {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Status {
+        Unspecified = 0,
+        Open = 1,
+        Closed = 30,
+    }
+}
+
+/// The status of a task.
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(i32)]
+#[enum_index(prost = "pb::Status", proto_definition = true)]
+pub enum Status {
+    #[index(0)]
+    Unspecified,
+    /// Still being worked on.
+    #[index(1)]
+    Open,
+    #[index(30)]
+    #[index_deprecated(3, 4)]
+    Closed,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(i32)]
+#[enum_index(prost = "pb::Status")]
+pub enum OpenOrClosed {
+    #[index(1)]
+    Open,
+    #[index(30)]
+    Closed,
+    #[index(skip)]
+    Internal,
+}
+
+#[test]
+fn test_prost_conversions() {
+    assert_eq!(pb::Status::from(Status::Closed), pb::Status::Closed);
+    assert_eq!(Status::try_from(pb::Status::Open).unwrap(), Status::Open);
+
+    // Variants are matched by name, hence agree with the indices as well.
+    assert_eq!(Status::Closed.index(), pb::Status::Closed as i32);
+}
+
+#[test]
+fn test_partial_prost_conversions() {
+    assert_eq!(
+        pb::Status::try_from(OpenOrClosed::Open).unwrap(),
+        pb::Status::Open
+    );
+    assert!(matches!(
+        pb::Status::try_from(OpenOrClosed::Internal),
+        Err(EnumIndexError::VariantSkipped(name)) if name == "Internal"
+    ));
+    assert!(matches!(
+        OpenOrClosed::try_from(pb::Status::Unspecified),
+        Err(EnumIndexError::NameNotFound(name)) if name == "Unspecified"
+    ));
+}
+
+#[test]
+fn test_proto_definition() {
+    assert_eq!(
+        Status::proto_definition(),
+        "\
+// The status of a task.
+enum Status {
+  STATUS_UNSPECIFIED = 0;
+  // Still being worked on.
+  STATUS_OPEN = 1;
+  STATUS_CLOSED = 30;
+  reserved 3, 4;
+}
+"
+    );
+}
@@ -0,0 +1,79 @@
+//! Tests for the `rkyv` feature.
+//!
+#![cfg(feature = "rkyv")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use rkyv::rancor::Error;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u32)]
+#[enum_index(rkyv = true)]
+enum Status {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(rkyv = true)]
+enum Colour {
+    #[index("red")]
+    Red,
+    #[index("green")]
+    Green,
+}
+
+#[test]
+fn test_round_trip() {
+    let bytes = rkyv::to_bytes::<Error>(&Status::Closed).unwrap();
+    let archived = rkyv::access::<ArchivedStatus, Error>(&bytes).unwrap();
+    assert_eq!(*archived.index(), 3);
+    assert_eq!(
+        rkyv::deserialize::<Status, Error>(archived).unwrap(),
+        Status::Closed
+    );
+
+    let bytes = rkyv::to_bytes::<Error>(&Colour::Green).unwrap();
+    let archived = rkyv::access::<ArchivedColour, Error>(&bytes).unwrap();
+    assert_eq!(archived.index().as_str(), "green");
+    assert_eq!(
+        rkyv::deserialize::<Colour, Error>(archived).unwrap(),
+        Colour::Green
+    );
+}
+
+#[test]
+fn test_archived_as_index() {
+    let bytes = rkyv::to_bytes::<Error>(&vec![Status::Open, Status::Closed]).unwrap();
+    let indices = rkyv::access::<rkyv::Archived<Vec<u32>>, Error>(&bytes).unwrap();
+    assert_eq!(indices.as_slice(), [1, 3]);
+
+    // Retired indices are still accepted.
+    let bytes = rkyv::to_bytes::<Error>(&vec![2u32]).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<Status>>, Error>(&bytes).unwrap();
+    assert_eq!(
+        rkyv::deserialize::<Vec<Status>, Error>(archived).unwrap(),
+        vec![Status::Closed]
+    );
+}
+
+#[test]
+fn test_unknown_index_fails_validation() {
+    let bytes = rkyv::to_bytes::<Error>(&vec![1u32, 4]).unwrap();
+    let err = rkyv::access::<rkyv::Archived<Vec<Status>>, Error>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("4"));
+
+    let bytes = rkyv::to_bytes::<Error>(&String::from("blue")).unwrap();
+    assert!(rkyv::access::<ArchivedColour, Error>(&bytes).is_err());
+}
+
+#[test]
+fn test_skipped_serialization_is_an_error() {
+    assert!(rkyv::to_bytes::<Error>(&Status::Unknown).is_err());
+}
@@ -0,0 +1,95 @@
+//! Tests for the `schemars::JsonSchema` implementation of the `schemars` feature.
+//!
+#![cfg(feature = "schemars")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use serde_json::json;
+
+/// The priority of a task.
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(schemars = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(schemars = true)]
+pub enum Status {
+    /// Still being worked on.
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+#[test]
+fn test_enum_schema() {
+    let schema = schemars::schema_for!(Priority);
+
+    assert_eq!(schema.get("type"), Some(&json!("integer")));
+    assert_eq!(schema.get("enum"), Some(&json!([1, 5])));
+    assert_eq!(
+        schema.get("description"),
+        Some(&json!("The priority of a task."))
+    );
+    assert_eq!(schema.get("title"), Some(&json!("Priority")));
+}
+
+#[test]
+fn test_documented_schema() {
+    let schema = schemars::schema_for!(Status);
+
+    assert_eq!(schema.get("type"), Some(&json!("string")));
+    assert_eq!(
+        schema.get("oneOf"),
+        Some(&json!([
+            { "const": "open", "description": "Still being worked on." },
+            { "const": "closed" },
+        ]))
+    );
+}
+
+#[test]
+fn test_schema_matches_serde() {
+    let schema = schemars::schema_for!(Priority);
+    let serialized = serde_json::to_value(Priority::High).unwrap();
+
+    assert!(schema
+        .get("enum")
+        .and_then(|values| values.as_array())
+        .unwrap()
+        .contains(&serialized));
+}
+
+/// Without `#[enum_index(schemars = true)]`, the enum is free to implement `JsonSchema`
+/// itself, even with the `schemars` feature enabled.
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+pub enum Custom {
+    #[index(1)]
+    Only,
+}
+impl schemars::JsonSchema for Custom {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Custom".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({ "const": "custom" })
+    }
+}
+
+#[test]
+fn test_opt_in() {
+    let schema = schemars::schema_for!(Custom);
+
+    assert_eq!(schema.get("const"), Some(&json!("custom")));
+}
@@ -0,0 +1,81 @@
+//! Tests for variants declared with `#[index(skip)]`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(display = "index")]
+enum Panicking {
+    #[index(1)]
+    Open,
+    #[index(skip)]
+    Uninitialized,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(skipped = "option")]
+enum Optional {
+    #[index("open")]
+    Open,
+    #[index(skip)]
+    __NonExhaustive,
+}
+
+#[test]
+fn test_try_index() {
+    assert_eq!(Panicking::Open.try_index(), Some(1));
+    assert_eq!(Panicking::Uninitialized.try_index(), None);
+    assert_eq!(Optional::Open.try_index(), Some("open"));
+    assert_eq!(Optional::__NonExhaustive.try_index(), None);
+}
+
+#[test]
+fn test_from_index_never_returns_skipped() {
+    assert_eq!(Panicking::from_index(&1), Some(Panicking::Open));
+    assert_eq!(Panicking::from_index(&0), None);
+    assert_eq!(Optional::from_index("skip"), None);
+    assert_eq!(Optional::match_prefix("skip"), None);
+
+    // Skipped variants still have a name.
+    assert_eq!(
+        Panicking::by_name("Uninitialized"),
+        Some(Panicking::Uninitialized)
+    );
+    assert_eq!(Panicking::Uninitialized.variant_name(), "Uninitialized");
+}
+
+#[test]
+fn test_panicking_index() {
+    assert_eq!(Panicking::Open.index(), 1);
+    assert_eq!(u8::from(Panicking::Open), 1);
+    assert!(std::panic::catch_unwind(|| Panicking::Uninitialized.index()).is_err());
+}
+
+#[test]
+fn test_optional_index() {
+    assert_eq!(Optional::Open.index(), Some("open"));
+    assert_eq!(Optional::__NonExhaustive.index(), None);
+
+    assert_eq!(<&'static str>::try_from(Optional::Open).unwrap(), "open");
+    assert_eq!(String::try_from(Optional::Open).unwrap(), "open");
+    assert!(matches!(
+        String::try_from(Optional::__NonExhaustive),
+        Err(EnumIndexError::VariantSkipped(_))
+    ));
+}
+
+#[test]
+fn test_skipped_serialization_is_an_error() {
+    assert_eq!(serde_json::to_string(&Panicking::Open).unwrap(), "1");
+    assert!(serde_json::to_string(&Panicking::Uninitialized).is_err());
+    assert!(serde_json::to_string(&Optional::__NonExhaustive).is_err());
+}
+
+#[test]
+fn test_skipped_display_falls_back_to_name() {
+    assert_eq!(Panicking::Open.to_string(), "1");
+    assert_eq!(Panicking::Uninitialized.to_string(), "Uninitialized");
+}
@@ -0,0 +1,58 @@
+//! Tests for `&'static str` and `Cow<'static, str>` index types.
+//!
+extern crate proc_macro;
+
+use std::borrow::Cow;
+
+use enum_index::prelude::*;
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+enum Level {
+    #[index("debug")]
+    Debug,
+    #[index("warn")]
+    Warn,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(Cow<'static, str>)]
+enum Channel {
+    #[index("stable")]
+    Stable,
+    #[index("nightly")]
+    Nightly,
+}
+
+#[test]
+fn test_static_str_index() {
+    let index: &'static str = Level::Warn.index();
+    assert_eq!(index, "warn");
+    assert_eq!(String::from(Level::Debug), "debug".to_string());
+}
+
+#[test]
+fn test_cow_index_is_borrowed() {
+    assert!(matches!(Channel::Nightly.index(), Cow::Borrowed("nightly")));
+    assert_eq!(String::from(Channel::Stable), "stable".to_string());
+}
+
+#[test]
+fn test_serde() {
+    assert_eq!(serde_json::to_string(&Level::Warn).unwrap(), "\"warn\"");
+    assert_eq!(
+        serde_json::from_str::<Level>("\"debug\"").ok(),
+        Some(Level::Debug)
+    );
+    assert!(serde_json::from_str::<Level>("\"error\"").is_err());
+
+    assert_eq!(
+        serde_json::to_string(&Channel::Stable).unwrap(),
+        "\"stable\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Channel>("\"nightly\"").ok(),
+        Some(Channel::Nightly)
+    );
+    assert!(serde_json::from_str::<Channel>("\"beta\"").is_err());
+}
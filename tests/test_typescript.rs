@@ -0,0 +1,82 @@
+//! Tests for `typescript_definition()`.
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+/// The status of a ticket.
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(typescript_definition = true)]
+enum Status {
+    /// Still being worked on.
+    #[index(1)]
+    Open,
+    /// Done, or abandoned.
+    ///
+    /// Closed tickets can be reopened.
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(typescript_definition = true)]
+enum Quote {
+    #[index("\"")]
+    Double,
+    #[index("it's")]
+    Single,
+}
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(i64)]
+#[enum_index(typescript_definition = true)]
+enum Large {
+    #[index(-1)]
+    Negative,
+    #[index(9007199254740993)]
+    Huge,
+}
+
+#[test]
+fn test_typescript_definition() {
+    assert_eq!(
+        Status::typescript_definition(),
+        "\
+/** The status of a ticket. */
+export const Status = {
+  /** Still being worked on. */
+  Open: 1,
+  /**
+   * Done, or abandoned.
+   *
+   * Closed tickets can be reopened.
+   */
+  Closed: 3,
+} as const;
+/** The status of a ticket. */
+export type Status = (typeof Status)[keyof typeof Status];
+"
+    );
+}
+
+#[test]
+fn test_typescript_literals() {
+    assert_eq!(
+        Quote::typescript_definition(),
+        "\
+export const Quote = {
+  Double: \"\\\"\",
+  Single: \"it's\",
+} as const;
+export type Quote = (typeof Quote)[keyof typeof Quote];
+"
+    );
+    assert!(
+        Large::typescript_definition().contains("  Negative: -1n,\n  Huge: 9007199254740993n,\n")
+    );
+}
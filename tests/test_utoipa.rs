@@ -0,0 +1,102 @@
+//! Tests for the `utoipa` implementations of the `utoipa` feature.
+//!
+#![cfg(feature = "utoipa")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use serde_json::json;
+use utoipa::openapi::schema::{Schema, SchemaType, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+/// The priority of a task.
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[enum_index(utoipa = true)]
+pub enum Priority {
+    #[index(1)]
+    Low,
+    #[index(5)]
+    High,
+    #[index(skip)]
+    Unknown,
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(&'static str)]
+#[enum_index(utoipa = true)]
+pub enum Status {
+    #[index("open")]
+    Open,
+    #[index("closed")]
+    Closed,
+}
+
+type OptionalU8 = Option<u8>;
+
+/// The priority of a task, if any.
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(OptionalU8)]
+#[enum_index(utoipa = true)]
+pub enum OptionalPriority {
+    #[index(None)]
+    Unset,
+    #[index(Some(1))]
+    Low,
+}
+
+fn object(schema: RefOr<Schema>) -> utoipa::openapi::Object {
+    match schema {
+        RefOr::T(Schema::Object(object)) => object,
+        _ => panic!("expected an object schema"),
+    }
+}
+
+#[test]
+fn test_integer_schema() {
+    let object = object(Priority::schema());
+
+    assert!(object.schema_type == SchemaType::Type(Type::Integer));
+    assert_eq!(object.enum_values, Some(vec![json!(1), json!(5)]));
+    assert_eq!(
+        object.description.as_deref(),
+        Some("The priority of a task.")
+    );
+    assert_eq!(Priority::name(), "Priority");
+}
+
+#[test]
+fn test_string_schema() {
+    let object = object(Status::schema());
+
+    assert!(object.schema_type == SchemaType::Type(Type::String));
+    assert_eq!(
+        object.enum_values,
+        Some(vec![json!("open"), json!("closed")])
+    );
+}
+
+#[test]
+fn test_schema_matches_serde() {
+    let object = object(Status::schema());
+    let serialized = serde_json::to_value(Status::Closed).unwrap();
+
+    assert!(object.enum_values.unwrap().contains(&serialized));
+}
+
+#[test]
+fn test_non_object_schema() {
+    let all_of = match OptionalPriority::schema() {
+        RefOr::T(Schema::AllOf(all_of)) => all_of,
+        _ => panic!("expected an allOf schema"),
+    };
+
+    assert!(matches!(all_of.items[0], RefOr::T(Schema::OneOf(_))));
+    let values = object(all_of.items[1].clone());
+    assert!(values.schema_type == SchemaType::AnyValue);
+    assert_eq!(values.enum_values, Some(vec![json!(null), json!(1)]));
+    assert_eq!(
+        all_of.description.as_deref(),
+        Some("The priority of a task, if any.")
+    );
+}
@@ -0,0 +1,165 @@
+//! Tests for versioned indices declared with #[index_versions(...)].
+//!
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[index_versions(v1, v2, v3)]
+pub enum Status {
+    #[index(1)]
+    Open,
+    #[index(v1 = 3, v2 = 30)]
+    Closed,
+    #[index(4, until = v2)]
+    Pending,
+    #[index(5, since = v2)]
+    Archived,
+}
+
+#[test]
+fn test_index_for() {
+    assert_eq!(Status::Open.index_for(StatusVersion::v1), Some(1));
+    assert_eq!(Status::Open.index_for(StatusVersion::v3), Some(1));
+
+    assert_eq!(Status::Closed.index_for(StatusVersion::v1), Some(3));
+    assert_eq!(Status::Closed.index_for(StatusVersion::v2), Some(30));
+    assert_eq!(Status::Closed.index_for(StatusVersion::v3), Some(30));
+
+    assert_eq!(Status::Pending.index_for(StatusVersion::v2), Some(4));
+    assert_eq!(Status::Pending.index_for(StatusVersion::v3), None);
+
+    assert_eq!(Status::Archived.index_for(StatusVersion::v1), None);
+    assert_eq!(Status::Archived.index_for(StatusVersion::v2), Some(5));
+}
+
+#[test]
+fn test_from_index_for() {
+    assert_eq!(
+        Status::from_index_for(StatusVersion::v1, &3),
+        Some(Status::Closed)
+    );
+    assert_eq!(Status::from_index_for(StatusVersion::v1, &30), None);
+    assert_eq!(
+        Status::from_index_for(StatusVersion::v2, &30),
+        Some(Status::Closed)
+    );
+    assert_eq!(Status::from_index_for(StatusVersion::v2, &3), None);
+
+    assert_eq!(Status::from_index_for(StatusVersion::v1, &5), None);
+    assert_eq!(Status::from_index_for(StatusVersion::v3, &4), None);
+    assert_eq!(
+        Status::from_index_for(StatusVersion::v3, &5),
+        Some(Status::Archived)
+    );
+}
+
+#[test]
+fn test_latest_version() {
+    assert_eq!(Status::Closed.index(), 30);
+    assert_eq!(Status::Pending.try_index(), None);
+    assert_eq!(Status::from_index(&30), Some(Status::Closed));
+    assert_eq!(Status::from_index(&4), None);
+
+    assert_eq!(
+        StatusVersion::ALL,
+        &[StatusVersion::v1, StatusVersion::v2, StatusVersion::v3]
+    );
+    assert!(StatusVersion::v1 < StatusVersion::v2);
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(String)]
+#[index_versions(v1, v2)]
+pub enum Command {
+    #[index(v1 = "quit", v2 = "exit")]
+    Exit,
+    #[index("help")]
+    Help,
+}
+
+#[test]
+fn test_string_versions() {
+    assert_eq!(
+        Command::Exit.index_for(CommandVersion::v1),
+        Some("quit".to_string())
+    );
+    assert_eq!(
+        Command::from_index_for(CommandVersion::v2, "exit"),
+        Some(Command::Exit)
+    );
+    assert_eq!(Command::from_index_for(CommandVersion::v2, "quit"), None);
+    assert_eq!(Command::Help.index(), "help");
+}
+
+#[test]
+fn test_retired_variant_index_panics() {
+    assert_eq!(Status::Pending.index_for(StatusVersion::v1), Some(4));
+    assert!(std::panic::catch_unwind(|| Status::Pending.index()).is_err());
+    assert!(serde_json::to_string(&Status::Pending).is_err());
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[index_versions(v1, v2)]
+#[enum_index(skipped = "option")]
+pub enum Method {
+    #[index(1)]
+    Get,
+    #[index(2, until = v1)]
+    #[index_deprecated(7)]
+    Head,
+}
+
+#[test]
+fn test_retired_variant_optional_index() {
+    assert_eq!(Method::Get.index(), Some(1));
+    assert_eq!(Method::Head.index(), None);
+    assert_eq!(Method::Head.index_for(MethodVersion::v1), Some(2));
+}
+
+#[test]
+fn test_from_index_for_deprecated() {
+    // `Head` is not present in the latest version, which `from_index()` follows.
+    assert_eq!(Method::from_index(&7), None);
+    assert!(!Method::is_deprecated_index(&7));
+    assert_eq!(Method::from_index_for(MethodVersion::v2, &7), None);
+    assert_eq!(
+        Method::from_index_for(MethodVersion::v1, &7),
+        Some(Method::Head)
+    );
+    assert_eq!(
+        Method::from_index_for(MethodVersion::v1, &2),
+        Some(Method::Head)
+    );
+    assert_eq!(Method::from_index_for(MethodVersion::v2, &2), None);
+}
+
+#[derive(EnumIndex, Debug, PartialEq)]
+#[index_type(u8)]
+#[index_versions(v1, v2, v3)]
+#[enum_index(skipped = "option")]
+pub enum Job {
+    #[index(1)]
+    Running,
+    #[index(4, until = v2)]
+    #[index_deprecated(9)]
+    Pending,
+    #[index(5, since = v2)]
+    #[index_deprecated(8)]
+    Archived,
+}
+
+#[test]
+fn test_from_index_for_deprecated_outside_range() {
+    // `Pending` is retired after v2, along with its retired index.
+    assert_eq!(Job::from_index_for(JobVersion::v2, &9), Some(Job::Pending));
+    assert_eq!(Job::from_index_for(JobVersion::v3, &9), None);
+    assert_eq!(Job::from_index(&9), None);
+
+    // `Archived` is introduced in v2, along with its retired index.
+    assert_eq!(Job::from_index_for(JobVersion::v1, &8), None);
+    assert_eq!(Job::from_index_for(JobVersion::v2, &8), Some(Job::Archived));
+    assert_eq!(Job::from_index(&8), Some(Job::Archived));
+}
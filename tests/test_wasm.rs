@@ -0,0 +1,47 @@
+//! Tests for the `wasm` feature.
+//!
+//! Only the conversions that do not call into JavaScript can be run outside of `wasm32`.
+#![cfg(feature = "wasm")]
+extern crate proc_macro;
+
+use enum_index::prelude::*;
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi};
+
+#[derive(Debug, EnumIndex, PartialEq)]
+#[index_type(u8)]
+#[enum_index(wasm = true)]
+enum Status {
+    #[index(1)]
+    Open,
+    #[index(3)]
+    #[index_deprecated(2)]
+    Closed,
+    #[index(skip)]
+    Unknown,
+}
+
+#[test]
+fn test_abi_round_trip() {
+    let abi = Status::Closed.into_abi();
+    assert_eq!(abi, 3u8.into_abi());
+    assert_eq!(unsafe { Status::from_abi(abi) }, Status::Closed);
+    assert_eq!(unsafe { Status::from_abi(2u8.into_abi()) }, Status::Closed);
+}
+
+#[test]
+fn test_optional_abi() {
+    let abi = None::<Status>.into_abi();
+    assert_eq!(abi, <u8 as OptionIntoWasmAbi>::none());
+    assert!(<Status as OptionFromWasmAbi>::is_none(&abi));
+    assert_eq!(unsafe { Option::<Status>::from_abi(abi) }, None);
+    assert_eq!(
+        unsafe { Option::<Status>::from_abi(Some(Status::Open).into_abi()) },
+        Some(Status::Open)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_skipped_variant_panics() {
+    Status::Unknown.into_abi();
+}